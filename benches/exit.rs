@@ -0,0 +1,84 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: forwarding the caller's guarantees
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: forwarding the caller's guarantees
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[cfg(unix)]
+fn success_status() -> std::process::ExitStatus {
+    std::os::unix::process::ExitStatusExt::from_raw(0)
+}
+
+#[cfg(windows)]
+fn success_status() -> std::process::ExitStatus {
+    std::os::windows::process::ExitStatusExt::from_raw(0)
+}
+
+fn success(c: &mut criterion::Criterion) {
+    let status = success_status();
+    c.bench_function("success", |b| {
+        b.iter(|| {
+            let allocated = allocations(|| {
+                let result = proc_exit::Code::from_status(std::hint::black_box(status)).ok();
+                assert!(proc_exit::report(result).is_ok());
+            });
+            assert_eq!(allocated, 0, "success path should not allocate");
+        });
+    });
+}
+
+fn failure(c: &mut criterion::Criterion) {
+    c.bench_function("failure_no_message", |b| {
+        b.iter(|| {
+            let allocated = allocations(|| {
+                let _ = std::hint::black_box(std::hint::black_box(proc_exit::Code::FAILURE).ok());
+            });
+            assert_eq!(
+                allocated, 0,
+                "failure without a message should not allocate"
+            );
+        });
+    });
+    c.bench_function("failure_with_static_message", |b| {
+        b.iter(|| {
+            let allocated = allocations(|| {
+                let _ = std::hint::black_box(
+                    std::hint::black_box(proc_exit::Code::FAILURE).with_message("failed"),
+                );
+            });
+            assert_eq!(allocated, 0, "a `&'static str` message should not allocate");
+        });
+    });
+    c.bench_function("failure_with_message", |b| {
+        b.iter(|| {
+            std::hint::black_box(proc_exit::Code::FAILURE)
+                .with_message(std::hint::black_box(String::new()))
+        });
+    });
+}
+
+criterion::criterion_group!(benches, success, failure);
+criterion::criterion_main!(benches);
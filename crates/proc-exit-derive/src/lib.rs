@@ -0,0 +1,143 @@
+//! > Derive exit codes for error enums
+//!
+//! See [`proc-exit`](https://docs.rs/proc-exit), with its `derive` feature.
+
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Map each variant of an error enum to a `proc_exit::Code`
+///
+/// Each variant takes an `#[exit_code(...)]` attribute with either an integer, for
+/// `Code::new`, or an expression evaluating to a `Code`, like `proc_exit::bash::USAGE`.
+///
+/// This generates:
+/// - `fn exit_code(&self) -> proc_exit::Code`
+/// - `impl From<Enum> for proc_exit::Exit`, with the code from `exit_code` and the enum's
+///   `Display` as the message
+///
+/// Example:
+///
+/// ```
+/// #[derive(Debug, proc_exit::ExitCode)]
+/// enum AppError {
+///     #[exit_code(proc_exit::sysexits::USAGE_ERR)]
+///     MissingInput,
+///     #[exit_code(3)]
+///     Conflict { path: String },
+///     #[exit_code(-1)]
+///     Crashed,
+/// }
+///
+/// impl std::fmt::Display for AppError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Self::MissingInput => write!(f, "missing `--input`"),
+///             Self::Conflict { path } => write!(f, "`{path}` was modified"),
+///             Self::Crashed => write!(f, "crashed"),
+///         }
+///     }
+/// }
+///
+/// let err = AppError::Conflict { path: "a.txt".to_owned() };
+/// assert_eq!(err.exit_code(), proc_exit::Code::new(3));
+/// assert_eq!(AppError::Crashed.exit_code(), proc_exit::Code::new(-1));
+///
+/// let exit = proc_exit::Exit::from(AppError::MissingInput);
+/// assert_eq!(exit.code(), proc_exit::sysexits::USAGE_ERR);
+/// assert_eq!(exit.to_string(), "missing `--input`");
+/// ```
+#[proc_macro_derive(ExitCode, attributes(exit_code))]
+pub fn derive_exit_code(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`ExitCode` can only be derived for enums",
+        ));
+    };
+
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let name = &variant.ident;
+        let code = variant_code(variant)?;
+        arms.push(quote! {
+            Self::#name { .. } => #code,
+        });
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // The message is the `Display` of the error, kept until reported
+    let mut from_generics = input.generics.clone();
+    let from_where_clause = from_generics.make_where_clause();
+    from_where_clause
+        .predicates
+        .push(syn::parse_quote! { #ty #ty_generics: ::core::fmt::Display + 'static });
+    Ok(quote! {
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// The code to exit with for this error
+            pub fn exit_code(&self) -> ::proc_exit::Code {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#ty #ty_generics> for ::proc_exit::Exit #from_where_clause {
+            fn from(err: #ty #ty_generics) -> Self {
+                err.exit_code().with_message(err)
+            }
+        }
+    })
+}
+
+fn variant_code(variant: &syn::Variant) -> syn::Result<TokenStream> {
+    let mut attrs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("exit_code"));
+    let Some(attr) = attrs.next() else {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            "missing `#[exit_code(...)]`",
+        ));
+    };
+    if let Some(duplicate) = attrs.next() {
+        return Err(syn::Error::new_spanned(
+            duplicate,
+            "duplicate `#[exit_code(...)]`",
+        ));
+    }
+    let expr: syn::Expr = attr.parse_args()?;
+    let code = if is_int_literal(&expr) {
+        quote! { ::proc_exit::Code::new(#expr) }
+    } else {
+        quote! { #expr }
+    };
+    Ok(code)
+}
+
+/// Whether `expr` is an integer literal, including a negative one like `-1`
+fn is_int_literal(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(_),
+            ..
+        }) => true,
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => is_int_literal(expr),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => is_int_literal(expr),
+        _ => false,
+    }
+}
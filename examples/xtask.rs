@@ -0,0 +1,34 @@
+//! An `xtask`-style runner, propagating the first failing command's exit code
+//!
+//! ```console
+//! $ cargo run --example xtask -- ci
+//! ```
+
+use proc_exit::prelude::*;
+
+fn main() {
+    let result = run();
+    proc_exit::exit(result);
+}
+
+fn run() -> ExitResult {
+    let task = std::env::args()
+        .nth(1)
+        .or_code(proc_exit::bash::USAGE, "usage: xtask <ci|fmt>")?;
+    match task.as_str() {
+        "ci" => proc_exit::run_all(&mut [
+            cargo(&["fmt", "--check"]),
+            cargo(&["clippy", "--", "-D", "warnings"]),
+            cargo(&["test"]),
+        ]),
+        "fmt" => proc_exit::run_all(&mut [cargo(&["fmt"])]),
+        _ => Err(proc_exit::bash::USAGE.with_message(format!("unknown task `{task}`"))),
+    }
+}
+
+fn cargo(args: &[&str]) -> std::process::Command {
+    let mut cmd =
+        std::process::Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cmd.args(args);
+    cmd
+}
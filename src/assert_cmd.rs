@@ -0,0 +1,33 @@
+//! Assert on a [`Code`][crate::Code] with [`assert_cmd`](https://docs.rs/assert_cmd)
+//!
+//! [`Assert::code`][::assert_cmd::assert::Assert::code] accepts a `Code` as-is, comparing the
+//! [raw][crate::Code::as_raw] value.
+//!
+//! Example:
+//!
+//! ```
+//! use assert_cmd::prelude::*;
+//!
+//! std::process::Command::new("sh")
+//!     .args(["-c", "exit 2"])
+//!     .assert()
+//!     .code(proc_exit::bash::USAGE);
+//!
+//! let result = std::process::Command::new("sh")
+//!     .args(["-c", "exit 3"])
+//!     .assert()
+//!     .try_code(proc_exit::bash::USAGE);
+//! assert!(result.is_err());
+//! ```
+
+/// Compare against the [raw][crate::Code::as_raw] value
+impl ::assert_cmd::assert::IntoCodePredicate<::assert_cmd::assert::EqCodePredicate>
+    for crate::Code
+{
+    type Predicate = ::assert_cmd::assert::EqCodePredicate;
+
+    #[inline]
+    fn into_code(self) -> Self::Predicate {
+        self.as_raw().into_code()
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::atomic::{AtomicI32, Ordering};
+
+/// A [`Code`][crate::Code] shared across threads, for recording the worst outcome without locking
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::{AtomicCode, Code};
+///
+/// let worst = AtomicCode::new(Code::SUCCESS);
+/// std::thread::scope(|s| {
+///     for i in 0..8 {
+///         let worst = &worst;
+///         s.spawn(move || {
+///             for _ in 0..1000 {
+///                 worst.merge_worst(Code::SUCCESS);
+///             }
+///             if i == 3 {
+///                 worst.merge_worst(proc_exit::bash::USAGE);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(worst.load(), proc_exit::bash::USAGE);
+/// ```
+///
+/// Threads racing with different failures end with the most severe, whichever order they land
+/// in:
+///
+/// ```
+/// use proc_exit::{AtomicCode, Code};
+///
+/// let codes = [
+///     Code::new(3),
+///     proc_exit::bash::USAGE,
+///     proc_exit::bash::SIGINT,
+///     proc_exit::bash::NOT_FOUND,
+/// ];
+/// for _ in 0..100 {
+///     let worst = AtomicCode::new(Code::SUCCESS);
+///     let barrier = std::sync::Barrier::new(codes.len());
+///     std::thread::scope(|s| {
+///         for code in codes {
+///             let worst = &worst;
+///             let barrier = &barrier;
+///             s.spawn(move || {
+///                 barrier.wait();
+///                 for _ in 0..100 {
+///                     worst.merge_worst(Code::SUCCESS);
+///                     worst.merge_worst(code);
+///                 }
+///             });
+///         }
+///     });
+///     assert_eq!(worst.load(), proc_exit::bash::SIGINT);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AtomicCode {
+    raw: AtomicI32,
+}
+
+impl AtomicCode {
+    /// Start from `code`, usually [`Code::SUCCESS`][crate::Code::SUCCESS]
+    pub const fn new(code: crate::Code) -> Self {
+        Self {
+            raw: AtomicI32::new(code.as_raw()),
+        }
+    }
+
+    /// The current code
+    ///
+    /// This uses [`Ordering::Acquire`], pairing with [`AtomicCode::merge_worst`].
+    pub fn load(&self) -> crate::Code {
+        crate::Code::new(self.raw.load(Ordering::Acquire))
+    }
+
+    /// Keep the [worst][crate::Code::worst] of the current code and `code`, returning the result
+    ///
+    /// This has the same semantics as [`Code::worst`][crate::Code::worst], with the current
+    /// code as `self`, so the most severe failure wins and, among equally severe ones, the first
+    /// to be merged.  It retries a compare-and-swap with [`Ordering::AcqRel`] until it succeeds,
+    /// so writes made before merging are visible to a thread that
+    /// [loads][AtomicCode::load] the merged code.
+    pub fn merge_worst(&self, code: crate::Code) -> crate::Code {
+        let mut current = self.raw.load(Ordering::Acquire);
+        loop {
+            let merged = crate::Code::new(current).worst(code).as_raw();
+            if merged == current {
+                return crate::Code::new(current);
+            }
+            match self.raw.compare_exchange_weak(
+                current,
+                merged,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return crate::Code::new(merged),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Unwrap the code
+    pub fn into_inner(self) -> crate::Code {
+        crate::Code::new(self.raw.into_inner())
+    }
+}
+
+impl Default for AtomicCode {
+    #[inline]
+    fn default() -> Self {
+        Self::new(crate::Code::SUCCESS)
+    }
+}
+
+impl From<crate::Code> for AtomicCode {
+    #[inline]
+    fn from(code: crate::Code) -> Self {
+        Self::new(code)
+    }
+}
@@ -67,6 +67,8 @@ pub const SIGFPE: crate::Code = crate::Code::new(SIGBASE + 8);
 /// immediately.  In contrast to `SIGTERM` and `SIGINT`, this signal cannot
 /// be caught or ignored, and the receiving process cannot perform any
 /// clean-up upon receiving this signal.
+///
+/// This is how the OOM killer ends a process, see [`Code::is_oom_likely`][crate::Code::is_oom_likely].
 pub const SIGKILL: crate::Code = crate::Code::new(SIGBASE + 9);
 
 /// The `SIGSEGV` signal is sent to a process on invalid memory reference
@@ -85,3 +87,71 @@ pub const SIGALRM: crate::Code = crate::Code::new(SIGBASE + 14);
 /// Unlike the `SIGKILL` signal, it can be caught and interpreted or
 /// ignored by the process.
 pub const SIGTERM: crate::Code = crate::Code::new(SIGBASE + 15);
+
+/// The named signal code for signal number `sig`, like [`SIGINT`] for `2`
+///
+/// Only the signals with a constant in this module are recognized, as other signal numbers
+/// vary between platforms.  See [`Code::from_status`][crate::Code::from_status] for a child
+/// process terminated by a signal.
+///
+/// Example:
+///
+/// ```
+/// assert_eq!(proc_exit::bash::signal_code(9), Some(proc_exit::bash::SIGKILL));
+/// assert_eq!(proc_exit::bash::signal_code(10), None);
+/// ```
+pub const fn signal_code(sig: i32) -> Option<crate::Code> {
+    match sig {
+        1 => Some(SIGHUP),
+        2 => Some(SIGINT),
+        3 => Some(SIGQUIT),
+        4 => Some(SIGILL),
+        5 => Some(SIGTRAP),
+        6 => Some(SIGABRT),
+        8 => Some(SIGFPE),
+        9 => Some(SIGKILL),
+        11 => Some(SIGSEGV),
+        13 => Some(SIGPIPE),
+        14 => Some(SIGALRM),
+        15 => Some(SIGTERM),
+        _ => None,
+    }
+}
+
+/// Codes the shell reserves for its own errors
+pub(crate) const fn is_reserved(code: crate::Code) -> bool {
+    let raw = code.as_raw();
+    raw == NOT_EXECUTABLE.as_raw()
+        || raw == NOT_FOUND.as_raw()
+        || raw == INVALID_EXIT.as_raw()
+        || raw == STATUS_OUT_OF_RANGE.as_raw()
+}
+
+/// Codes the shell reports for a process terminated by a signal
+pub(crate) const fn is_signal(code: crate::Code) -> bool {
+    SIGBASE < code.as_raw() && code.as_raw() < STATUS_OUT_OF_RANGE.as_raw()
+}
+
+pub(crate) const NAMES: &[crate::name::Entry] = &[
+    crate::name::Entry::new("USAGE", USAGE, "usage error"),
+    crate::name::Entry::new("NOT_EXECUTABLE", NOT_EXECUTABLE, "command not executable"),
+    crate::name::Entry::new("NOT_FOUND", NOT_FOUND, "command not found"),
+    crate::name::Entry::new("INVALID_EXIT", INVALID_EXIT, "invalid argument to exit"),
+    crate::name::Entry::new(
+        "STATUS_OUT_OF_RANGE",
+        STATUS_OUT_OF_RANGE,
+        "exit status out of range",
+    ),
+    crate::name::Entry::new("SIGHUP", SIGHUP, "hangup"),
+    crate::name::Entry::new("SIGINT", SIGINT, "interrupt"),
+    crate::name::Entry::new("SIGQUIT", SIGQUIT, "quit"),
+    crate::name::Entry::new("SIGILL", SIGILL, "illegal instruction"),
+    crate::name::Entry::new("SIGTRAP", SIGTRAP, "trace/breakpoint trap"),
+    crate::name::Entry::new("SIGABRT", SIGABRT, "aborted"),
+    crate::name::Entry::new("SIGFPE", SIGFPE, "floating point exception"),
+    crate::name::Entry::new("SIGKILL", SIGKILL, "likely killed by OOM killer (SIGKILL)"),
+    crate::name::Entry::new("SIGSEGV", SIGSEGV, "segmentation fault"),
+    crate::name::Entry::new("SIGPIPE", SIGPIPE, "broken pipe"),
+    crate::name::Entry::new("SIGALRM", SIGALRM, "alarm clock"),
+    crate::name::Entry::new("SIGTERM", SIGTERM, "terminated"),
+];
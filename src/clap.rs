@@ -0,0 +1,65 @@
+//! Accept [`Code`][crate::Code] as a [`clap`](https://docs.rs/clap) argument
+//!
+//! Example:
+//!
+//! ```
+//! use clap::Parser;
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     /// Code to exit with on failure
+//!     #[arg(long, value_parser = proc_exit::clap::code_parser())]
+//!     code: proc_exit::Code,
+//! }
+//!
+//! let args = Args::parse_from(["app", "--code", "EX_USAGE"]);
+//! assert_eq!(args.code, proc_exit::sysexits::USAGE_ERR);
+//!
+//! let args = Args::parse_from(["app", "--code", "3"]);
+//! assert_eq!(args.code, proc_exit::Code::new(3));
+//! ```
+
+/// Parse a [`Code`][crate::Code] from an integer or symbolic name
+///
+/// See [`Code`'s `FromStr`][crate::Code#impl-FromStr-for-Code] for the accepted syntax.
+#[inline]
+pub fn code_parser() -> CodeValueParser {
+    CodeValueParser::new()
+}
+
+/// [`TypedValueParser`][::clap::builder::TypedValueParser] for [`Code`][crate::Code]
+#[derive(Copy, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CodeValueParser {}
+
+impl CodeValueParser {
+    /// Parse a [`Code`][crate::Code] from an integer or symbolic name
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ::clap::builder::TypedValueParser for CodeValueParser {
+    type Value = crate::Code;
+
+    fn parse_ref(
+        &self,
+        cmd: &::clap::Command,
+        arg: Option<&::clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, ::clap::Error> {
+        let value = value.to_str().ok_or_else(|| {
+            ::clap::Error::new(::clap::error::ErrorKind::InvalidUtf8).with_cmd(cmd)
+        })?;
+        value.parse::<crate::Code>().map_err(|err| {
+            let arg = arg
+                .map(|a| a.to_string())
+                .unwrap_or_else(|| "...".to_owned());
+            ::clap::Error::raw(
+                ::clap::error::ErrorKind::ValueValidation,
+                format!("invalid value '{value}' for '{arg}': {err}\n"),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
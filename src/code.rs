@@ -10,24 +10,262 @@ pub struct Code(i32);
 /// # Create a [`Code`]
 impl Code {
     /// The process exited successfully.
-    pub const SUCCESS: Code = Code(0);
+    pub const SUCCESS: Code = Code::new(0);
 
     /// Generic failure.
-    pub const FAILURE: Code = Code(1);
+    pub const FAILURE: Code = Code::new(1);
 
     /// Create a custom error code
     pub const fn new(code: i32) -> Self {
         Self(code)
     }
 
+    /// Create a custom error code, if allowed by `policy`
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{Code, CodePolicy};
+    ///
+    /// assert_eq!(Code::new_with_policy(3, CodePolicy::PORTABLE), Ok(Code::new(3)));
+    /// assert!(Code::new_with_policy(256, CodePolicy::PORTABLE).is_err());
+    /// ```
+    #[inline]
+    pub const fn new_with_policy(
+        code: i32,
+        policy: crate::CodePolicy,
+    ) -> Result<Self, crate::PolicyError> {
+        policy.check(Self::new(code))
+    }
+
+    /// Create a custom error code, if it is [portable][Code::is_portable]
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// assert_eq!(Code::try_new_portable(3), Ok(Code::new(3)));
+    /// let err = Code::try_new_portable(256).unwrap_err();
+    /// assert_eq!(err.to_string(), "exit code 256 out of range 0..=255");
+    /// ```
+    #[inline]
+    pub const fn try_new_portable(code: i32) -> Result<Self, CodeRangeError> {
+        let code = Self::new(code);
+        if code.is_portable() {
+            Ok(code)
+        } else {
+            Err(CodeRangeError::new(code.as_raw() as i128, 0, 255))
+        }
+    }
+
+    /// Converts [`std::process::ExitStatus`] to [`Code`], if it has one
+    ///
+    /// This is [`Code::from_status`] without the fallback to [`Code::default`].  `None` is
+    /// returned when the status has no code:
+    /// - On Unix, for a continued process, from `waitpid` with `WCONTINUED`, as it hasn't ended.
+    ///   A stopped process is reported as the signal that stopped it.  Otherwise, only for a
+    ///   status that is neither an exit nor a signal, which is only expected when created with
+    ///   `ExitStatusExt::from_raw`.
+    /// - On Windows, never.
+    /// - Elsewhere, whenever [`ExitStatus::code`][std::process::ExitStatus::code] is `None`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let status = std::process::Command::new("true").status().unwrap();
+    /// assert_eq!(proc_exit::Code::try_from_status(status), Some(proc_exit::Code::SUCCESS));
+    /// ```
+    #[inline]
+    pub fn try_from_status(status: std::process::ExitStatus) -> Option<Self> {
+        if status.success() {
+            return Some(Self::SUCCESS);
+        }
+        // `success` is authoritative, so a failure is never reported as `0`
+        platform_exit_code(status)
+            .filter(|raw| *raw != 0)
+            .map(Self::new)
+    }
+
+    /// Every [portable][Code::is_portable] code, in ascending order
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// assert!(proc_exit::Code::portable_iter().all(|c| c.as_portable().is_some()));
+    /// assert_eq!(proc_exit::Code::portable_iter().count(), 256);
+    /// ```
+    #[inline]
+    pub fn portable_iter() -> impl Iterator<Item = Self> {
+        (0..=u8::MAX).map(Self::from)
+    }
+
+    /// Converts an `errno` value to a [`sysexits`][crate::sysexits] [`Code`]
+    ///
+    /// This uses the same classification as
+    /// [`ToSysexitsResultExt`][crate::sysexits::ToSysexitsResultExt], falling back to
+    /// [`sysexits::IO_ERR`][crate::sysexits::IO_ERR].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// const ENOENT: i32 = 2;
+    /// assert_eq!(proc_exit::Code::from_errno(ENOENT), proc_exit::sysexits::OS_FILE_ERR);
+    /// ```
+    #[cfg(unix)]
+    #[inline]
+    pub fn from_errno(errno: i32) -> Self {
+        // Classifying an OS error doesn't allocate
+        let kind = std::io::Error::from_raw_os_error(errno).kind();
+        crate::sysexits::io_to_code(kind)
+    }
+
+    /// Code for a caught panic
+    ///
+    /// This is [`sysexits::SOFTWARE_ERR`][crate::sysexits::SOFTWARE_ERR] unless the panic was
+    /// raised with [`std::panic::panic_any`] and a [`Code`].  Inspecting the payload is
+    /// best-effort; anything else is treated as an internal error.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// let payload = std::panic::catch_unwind(|| {
+    ///     std::panic::panic_any(proc_exit::bash::USAGE);
+    /// })
+    /// .unwrap_err();
+    /// assert_eq!(Code::from_panic(payload.as_ref()), proc_exit::bash::USAGE);
+    ///
+    /// let payload = std::panic::catch_unwind(|| panic!("oops")).unwrap_err();
+    /// assert_eq!(Code::from_panic(payload.as_ref()), proc_exit::sysexits::SOFTWARE_ERR);
+    /// ```
+    pub fn from_panic(payload: &(dyn std::any::Any + Send)) -> Self {
+        payload
+            .downcast_ref::<Self>()
+            .copied()
+            .unwrap_or(crate::sysexits::SOFTWARE_ERR)
+    }
+
     /// Converts [`std::process::ExitStatus`] to [`Code`].
     ///
     /// On Unix, if the process was terminated by a fatal signal, the corresponding
-    /// signal exit code is returned.
+    /// signal exit code is returned.  A stopped process, from `waitpid` with `WUNTRACED`, is
+    /// reported the same way, as the signal that stopped it.  A continued process, from
+    /// `WCONTINUED`, hasn't ended and has no code, so the fallback is used.
+    ///
+    /// The result [is ok][Code::is_ok] exactly when
+    /// [`ExitStatus::success`][std::process::ExitStatus::success] is `true`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// # #[cfg(target_os = "linux")] {
+    /// use std::os::unix::process::ExitStatusExt as _;
+    ///
+    /// // `WIFCONTINUED`
+    /// let status = std::process::ExitStatus::from_raw(0xffff);
+    /// assert_eq!(proc_exit::Code::from_status(status), proc_exit::Code::FAILURE);
+    /// assert_eq!(proc_exit::Code::try_from_status(status), None);
+    /// # }
+    /// ```
+    ///
+    /// The raw wait status layout is platform-specific, so these are only checked on Linux and
+    /// Windows:
+    ///
+    /// ```
+    /// # #[cfg(target_os = "linux")] {
+    /// use std::os::unix::process::ExitStatusExt as _;
+    /// use std::process::ExitStatus;
+    /// use proc_exit::Code;
+    ///
+    /// // `WIFEXITED`
+    /// assert_eq!(Code::from_status(ExitStatus::from_raw(3 << 8)), Code::new(3));
+    /// // `WIFSIGNALED`, with `SIGKILL`
+    /// assert_eq!(Code::from_status(ExitStatus::from_raw(9)), Code::new(9));
+    /// // `WIFSTOPPED`, with `SIGTSTP`
+    /// assert_eq!(Code::from_status(ExitStatus::from_raw((20 << 8) | 0x7f)), Code::new(20));
+    /// // None of the above, so the fallback is used
+    /// assert_eq!(Code::from_status(ExitStatus::from_raw(0xff)), Code::FAILURE);
+    /// assert_eq!(Code::try_from_status(ExitStatus::from_raw(0xff)), None);
+    ///
+    /// for raw in 0..=0xffff {
+    ///     let status = ExitStatus::from_raw(raw);
+    ///     assert_eq!(Code::from_status(status).is_ok(), status.success(), "{raw:#x}");
+    /// }
+    /// # }
+    /// # #[cfg(windows)] {
+    /// use std::os::windows::process::ExitStatusExt as _;
+    /// use std::process::ExitStatus;
+    /// use proc_exit::Code;
+    ///
+    /// assert_eq!(Code::from_status(ExitStatus::from_raw(3)), Code::new(3));
+    /// // `STATUS_ACCESS_VIOLATION`
+    /// let code = Code::from_status(ExitStatus::from_raw(0xC0000005));
+    /// assert_eq!(code.as_unsigned(), 0xC0000005);
+    /// assert_eq!(Code::try_from_status(ExitStatus::from_raw(0xC0000005)), Some(code));
+    ///
+    /// for raw in [0, 1, 255, 0xC0000005, u32::MAX] {
+    ///     let status = ExitStatus::from_raw(raw);
+    ///     assert_eq!(Code::from_status(status).is_ok(), status.success(), "{raw:#x}");
+    /// }
+    /// # }
+    /// ```
     #[inline]
     pub fn from_status(status: std::process::ExitStatus) -> Self {
         Self::from(status)
     }
+
+    /// Converts the result of running a [`std::process::Command`] to an [`ExitResult`][crate::ExitResult]
+    ///
+    /// A failure to run the command is reported as [`Code::FAILURE`] with the error as the message
+    /// while a non-zero status is passed along as-is, see [`Code::from_status`].
+    ///
+    /// This fills the role of the unstable `ExitStatus::exit_ok`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// fn run() -> proc_exit::ExitResult {
+    ///     let status = std::process::Command::new("true").status();
+    ///     proc_exit::Code::from_status_result(status)?;
+    ///     proc_exit::Code::SUCCESS.ok()
+    /// }
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    #[inline]
+    pub fn from_status_result(
+        result: Result<std::process::ExitStatus, std::io::Error>,
+    ) -> crate::ExitResult {
+        match result {
+            Ok(status) => Self::from_status(status).ok(),
+            Err(err) => Err(Self::FAILURE.with_message(err)),
+        }
+    }
+
+    /// Parse the environment variable `name`, if set
+    ///
+    /// The value is parsed like [`Code`'s `FromStr`][Code#impl-FromStr-for-Code], accepting
+    /// integers and names.  A value that isn't valid UTF-8 is reported as a parse error.
+    ///
+    /// Unlike [`Reporter::env_override`][crate::Reporter::env_override], this only reads the
+    /// code, leaving what to do with it to the caller.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// let expected = match Code::parse_env("MYTOOL_EXPECTED_CODE") {
+    ///     Some(Ok(code)) => code,
+    ///     Some(Err(err)) => panic!("MYTOOL_EXPECTED_CODE: {err}"),
+    ///     None => Code::SUCCESS,
+    /// };
+    /// ```
+    pub fn parse_env(name: &str) -> Option<Result<Self, ParseCodeError>> {
+        let value = std::env::var_os(name)?;
+        Some(value.to_string_lossy().parse())
+    }
 }
 
 /// # Bubble up the exit [`Code`]
@@ -39,6 +277,18 @@ impl Code {
     }
 
     /// Convert to [`Result`]
+    ///
+    /// Forgetting to propagate the failure is caught by the `unused_must_use` lint:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    ///
+    /// fn run() -> proc_exit::ExitResult {
+    ///     proc_exit::Code::FAILURE.ok();
+    ///     proc_exit::Code::SUCCESS.ok()
+    /// }
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
     #[inline]
     pub fn ok(self) -> crate::ExitResult {
         if self.as_raw() == Self::SUCCESS.as_raw() {
@@ -48,6 +298,117 @@ impl Code {
         }
     }
 
+    /// Convert to [`Result`], treating any of `acceptable` as success
+    ///
+    /// For tools where a non-zero code can still mean the run was fine, like `diff` exiting with
+    /// `1` when the inputs differ.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// const DIFF_OK: &[Code] = &[Code::SUCCESS, Code::new(1)];
+    /// assert!(Code::new(1).ok_if_one_of(DIFF_OK).is_ok());
+    /// assert!(Code::new(2).ok_if_one_of(DIFF_OK).is_err());
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    #[inline]
+    pub fn ok_if_one_of(self, acceptable: &[Code]) -> crate::ExitResult {
+        if self.is_one_of(acceptable) {
+            Ok(())
+        } else {
+            Err(crate::Exit::new(self))
+        }
+    }
+
+    /// Convert to [`Result`], carrying `value` on success and `msg` on failure
+    ///
+    /// `msg` is only boxed on failure, but it is still built on success.  Use
+    /// [`Code::to_result_with_else`] when building it is expensive, like with `format!`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// assert_eq!(Code::SUCCESS.to_result_with(5, "unused").unwrap(), 5);
+    /// let err = Code::FAILURE.to_result_with(5, "build failed").unwrap_err();
+    /// assert_eq!(err.to_string(), "build failed");
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    #[inline]
+    pub fn to_result_with<T, D: std::fmt::Display + 'static>(
+        self,
+        value: T,
+        msg: D,
+    ) -> Result<T, crate::Exit> {
+        if self.is_ok() {
+            Ok(value)
+        } else {
+            Err(self.with_message(msg))
+        }
+    }
+
+    /// Convert to [`Result`], carrying `value` on success and the message from `msg` on failure
+    ///
+    /// `msg` is only called on failure.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// let action = "build";
+    /// assert_eq!(
+    ///     Code::SUCCESS
+    ///         .to_result_with_else(5, || -> String { unreachable!() })
+    ///         .unwrap(),
+    ///     5
+    /// );
+    /// let err = Code::FAILURE
+    ///     .to_result_with_else(5, || format!("{action} failed"))
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "build failed");
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    #[inline]
+    pub fn to_result_with_else<T, D: std::fmt::Display + 'static>(
+        self,
+        value: T,
+        msg: impl FnOnce() -> D,
+    ) -> Result<T, crate::Exit> {
+        if self.is_ok() {
+            Ok(value)
+        } else {
+            Err(self.with_message(msg()))
+        }
+    }
+
+    /// The more severe of two codes, for aggregating results
+    ///
+    /// Codes are compared by [`Code::severity`], so a failure is worse than success and a signal
+    /// is worse than an application's failure.  On a tie, `self` is kept so the earliest of
+    /// equally severe failures wins.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// assert_eq!(Code::SUCCESS.worst(proc_exit::bash::USAGE), proc_exit::bash::USAGE);
+    /// assert_eq!(Code::new(3).worst(proc_exit::bash::USAGE), Code::new(3));
+    /// assert_eq!(Code::new(3).worst(proc_exit::bash::SIGKILL), proc_exit::bash::SIGKILL);
+    /// ```
+    #[inline]
+    pub const fn worst(self, other: Self) -> Self {
+        if self.severity() < other.severity() {
+            other
+        } else {
+            self
+        }
+    }
+
     /// Convert to [`Exit`][crate::Exit] error type
     #[inline]
     pub fn as_exit(self) -> crate::Exit {
@@ -59,22 +420,123 @@ impl Code {
     pub fn with_message<D: std::fmt::Display + 'static>(self, msg: D) -> crate::Exit {
         self.as_exit().with_message(msg)
     }
+
+    /// Add a user-visible message rendered in the user's locale, see
+    /// [`Exit::with_message_fn`][crate::Exit::with_message_fn]
+    #[inline]
+    pub fn with_message_fn<F: Fn(&str) -> String + 'static>(self, message: F) -> crate::Exit {
+        self.as_exit().with_message_fn(message)
+    }
+
+    /// Convert to [`Exit`][crate::Exit], using [`Code::describe`] as the message
+    ///
+    /// Codes without a description get no message.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::sysexits::NO_PERM.with_default_message();
+    /// assert_eq!(exit.to_string(), "permission denied");
+    /// assert_eq!(proc_exit::Code::new(42).with_default_message().to_message_string(), None);
+    /// ```
+    #[inline]
+    pub fn with_default_message(self) -> crate::Exit {
+        match self.describe() {
+            Some(description) => self.with_message(description),
+            None => self.as_exit(),
+        }
+    }
+
+    /// Suggest how the user might resolve the failure, see [`Exit::with_hint`][crate::Exit::with_hint]
+    #[inline]
+    pub fn with_hint<D: std::fmt::Display + 'static>(self, hint: D) -> crate::Exit {
+        self.as_exit().with_hint(hint)
+    }
 }
 
 /// # Introspection and Integration
 impl Code {
     /// Convert to [`ExitCode`][std::process::ExitCode]
+    ///
+    /// Returns `None` for non-portable codes as `ExitCode` can only be built from a `u8` on
+    /// stable Rust, even on Windows where the full value could be delivered.  Building one from
+    /// the full value needs `ExitCodeExt::from_raw`, which is unstable, see
+    /// [rust-lang/rust#111688](https://github.com/rust-lang/rust/issues/111688).  Use
+    /// [`Code::process_exit`] to exit with a non-portable code.
     #[inline]
     pub fn as_exit_code(self) -> Option<std::process::ExitCode> {
         self.as_portable().map(|c| c.into())
     }
 
     /// Convert to raw value
+    ///
+    /// This is the signed interpretation, so large Windows codes are negative.  See
+    /// [`Code::as_unsigned`] for the conventional form of those.
     #[inline]
     pub const fn as_raw(self) -> i32 {
         self.0
     }
 
+    /// Convert to raw value, reinterpreted as unsigned
+    ///
+    /// Windows codes, especially `NTSTATUS` codes from crashes, are conventionally shown as
+    /// unsigned hex.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// // STATUS_ACCESS_VIOLATION
+    /// let code = proc_exit::Code::new(0xC0000005_u32 as i32);
+    /// assert_eq!(code.as_raw(), -1073741819);
+    /// assert_eq!(format!("{:#X}", code.as_unsigned()), "0xC0000005");
+    /// ```
+    #[inline]
+    pub const fn as_unsigned(self) -> u32 {
+        self.as_raw() as u32
+    }
+
+    /// Convert to raw value, if it is [portable][Code::is_portable]
+    ///
+    /// Unlike [`Code::as_raw`], this checks portability and, unlike [`Code::as_portable`], this
+    /// keeps the `i32` type.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// assert_eq!(Code::new(2).as_raw_checked(), Some(2));
+    /// assert_eq!(Code::new(-1).as_raw_checked(), None);
+    /// ```
+    #[inline]
+    pub const fn as_raw_checked(self) -> Option<i32> {
+        if self.is_portable() {
+            Some(self.as_raw())
+        } else {
+            None
+        }
+    }
+
+    /// Compare against a raw value
+    ///
+    /// This is a convenience for assertions and interop with APIs that deal in raw integers.
+    /// Prefer comparing against a [`Code`] constant.  This is a method rather than a
+    /// `PartialEq<i32>` impl so `code == n.into()` stays unambiguous.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// assert!(proc_exit::bash::USAGE.eq_raw(2));
+    /// assert!(!proc_exit::bash::USAGE.eq_raw(1));
+    ///
+    /// let raw: i32 = 2;
+    /// assert!(proc_exit::bash::USAGE == raw.into());
+    /// ```
+    #[inline]
+    pub const fn eq_raw(self, raw: i32) -> bool {
+        self.as_raw() == raw
+    }
+
     /// Convert to portable, raw value
     #[inline]
     pub const fn as_portable(self) -> Option<u8> {
@@ -85,6 +547,49 @@ impl Code {
         }
     }
 
+    /// Convert to [`PortableCode`][crate::PortableCode], if it is [portable][Code::is_portable]
+    ///
+    /// Like [`Code::as_portable`] but the result carries the guarantee in its type and the error
+    /// explains the failure.  See [`Code::try_new_portable`] to validate a raw value while
+    /// staying a [`Code`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// assert_eq!(Code::new(3).try_into_portable().unwrap().get(), 3);
+    /// let err = Code::new(256).try_into_portable().unwrap_err();
+    /// assert_eq!(err.to_string(), "exit code 256 out of range 0..=255");
+    /// ```
+    #[inline]
+    pub const fn try_into_portable(self) -> Result<crate::PortableCode, CodeRangeError> {
+        match self.as_portable() {
+            Some(code) => Ok(crate::PortableCode::new(code)),
+            None => Err(CodeRangeError::new(self.as_raw() as i128, 0, 255)),
+        }
+    }
+
+    /// The code the current platform would actually deliver to the parent process
+    ///
+    /// On Unix, only the low 8-bits are reported, so `256` is observed as `0` and `-1` as `255`.
+    /// Elsewhere, the code is passed through unchanged.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let code = proc_exit::Code::new(258);
+    /// if cfg!(unix) {
+    ///     assert_eq!(code.normalize(), proc_exit::Code::new(2));
+    /// } else {
+    ///     assert_eq!(code.normalize(), code);
+    /// }
+    /// ```
+    #[inline]
+    pub const fn normalize(self) -> Self {
+        Self::new(platform_normalize(self.as_raw()))
+    }
+
     /// Determines if the provided [`std::process::ExitStatus`] was successful.
     ///
     /// Example:
@@ -121,6 +626,217 @@ impl Code {
         !self.is_ok()
     }
 
+    /// Determines if the code is any of `codes`
+    ///
+    /// Unlike [`Code::is_ok`], this lets the caller decide which codes are acceptable.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// assert!(Code::new(1).is_one_of(&[Code::SUCCESS, Code::new(1)]));
+    /// assert!(!Code::new(2).is_one_of(&[Code::SUCCESS, Code::new(1)]));
+    /// ```
+    #[inline]
+    pub fn is_one_of(self, codes: &[Code]) -> bool {
+        codes.contains(&self)
+    }
+
+    /// The symbolic name for a well-known code
+    ///
+    /// Names cover [`Code::SUCCESS`], [`Code::FAILURE`], the [`bash`][crate::bash] constants
+    /// (e.g. `SIGINT`), and the C names for [`sysexits`][crate::sysexits] (e.g. `EX_USAGE`).
+    /// When a code has multiple names, the first of these is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// assert_eq!(proc_exit::bash::SIGINT.name(), Some("SIGINT"));
+    /// assert_eq!(proc_exit::sysexits::USAGE_ERR.name(), Some("EX_USAGE"));
+    /// assert_eq!(proc_exit::Code::new(42).name(), None);
+    /// ```
+    #[inline]
+    pub fn name(self) -> Option<&'static str> {
+        crate::name::lookup_code(self).map(|e| e.name)
+    }
+
+    /// Render as a symbolic name when known, falling back to the number
+    ///
+    /// When a code has multiple names, `convention` decides which is used:
+    /// - [`Convention::Bash`]: [`Code::name`]'s precedence, e.g. `SUCCESS` for `0`
+    /// - [`Convention::Sysexits`]: the [`sysexits`][crate::sysexits] name first, e.g. `EX_OK` for `0`
+    ///
+    /// The result always parses back to the same [`Code`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{Code, Convention};
+    ///
+    /// assert_eq!(Code::SUCCESS.to_string_symbolic(Convention::Bash), "SUCCESS");
+    /// assert_eq!(Code::SUCCESS.to_string_symbolic(Convention::Sysexits), "EX_OK");
+    /// assert_eq!(Code::new(42).to_string_symbolic(Convention::Bash), "42");
+    ///
+    /// let code = proc_exit::bash::SIGINT;
+    /// assert_eq!(code.to_string_symbolic(Convention::Bash).parse::<Code>().unwrap(), code);
+    /// ```
+    pub fn to_string_symbolic(self, convention: Convention) -> String {
+        match crate::name::lookup_code_with(self, convention) {
+            Some(entry) => entry.name.to_owned(),
+            None => self.as_raw().to_string(),
+        }
+    }
+
+    /// A short, human-readable description of a well-known code
+    ///
+    /// This covers the same codes as [`Code::name`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// assert_eq!(proc_exit::sysexits::NO_PERM.describe(), Some("permission denied"));
+    /// ```
+    #[inline]
+    pub fn describe(self) -> Option<&'static str> {
+        crate::name::lookup_code(self).map(|e| e.description)
+    }
+
+    /// Classify the code by the convention it most likely follows
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{Code, CodeCategory};
+    ///
+    /// assert_eq!(Code::SUCCESS.category(), CodeCategory::Success);
+    /// assert_eq!(proc_exit::bash::USAGE.category(), CodeCategory::Usage);
+    /// assert_eq!(proc_exit::sysexits::IO_ERR.category(), CodeCategory::Sysexit);
+    /// assert_eq!(proc_exit::bash::SIGINT.category(), CodeCategory::Signal);
+    /// assert_eq!(Code::new(42).category(), CodeCategory::Other);
+    /// ```
+    pub const fn category(self) -> CodeCategory {
+        if self.is_ok() {
+            CodeCategory::Success
+        } else if self.as_raw() == Self::FAILURE.as_raw() {
+            CodeCategory::GenericFailure
+        } else if self.as_raw() == crate::bash::USAGE.as_raw()
+            || self.as_raw() == crate::sysexits::USAGE_ERR.as_raw()
+        {
+            CodeCategory::Usage
+        } else if crate::sysexits::is_sysexit(self) {
+            CodeCategory::Sysexit
+        } else if crate::bash::is_signal(self) {
+            CodeCategory::Signal
+        } else if crate::bash::is_reserved(self) {
+            CodeCategory::ShellReserved
+        } else {
+            CodeCategory::Other
+        }
+    }
+
+    /// How bad the outcome is, based on its [category][Code::category]
+    ///
+    /// | Severity | Categories |
+    /// |----------|------------|
+    /// | `0`      | [`Success`][CodeCategory::Success] |
+    /// | `1`      | [`GenericFailure`][CodeCategory::GenericFailure], [`Usage`][CodeCategory::Usage], [`Sysexit`][CodeCategory::Sysexit], [`Other`][CodeCategory::Other] |
+    /// | `2`      | [`ShellReserved`][CodeCategory::ShellReserved], as the program could not run correctly |
+    /// | `3`      | [`Signal`][CodeCategory::Signal], as the process died |
+    ///
+    /// See [`Code::by_severity`] for sorting and [`Code::worst`] for picking between two codes.
+    #[inline]
+    pub const fn severity(self) -> u8 {
+        match self.category() {
+            CodeCategory::Success => 0,
+            CodeCategory::GenericFailure
+            | CodeCategory::Usage
+            | CodeCategory::Sysexit
+            | CodeCategory::Other => 1,
+            CodeCategory::ShellReserved => 2,
+            CodeCategory::Signal => 3,
+        }
+    }
+
+    /// Compare codes by [`Code::severity`]
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Code;
+    ///
+    /// let mut codes = vec![proc_exit::bash::SIGINT, Code::new(3), Code::SUCCESS];
+    /// codes.sort_by(Code::by_severity);
+    /// assert_eq!(codes, [Code::SUCCESS, Code::new(3), proc_exit::bash::SIGINT]);
+    /// ```
+    #[inline]
+    pub fn by_severity(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.severity().cmp(&b.severity())
+    }
+
+    /// Determines if the process was likely killed for running out of memory
+    ///
+    /// The Linux OOM killer, and container runtimes enforcing a memory limit, end a process with
+    /// `SIGKILL`, which a shell reports as [`bash::SIGKILL`][crate::bash::SIGKILL] (`137`).  This is
+    /// a heuristic: `SIGKILL` also comes from `kill -9`, timeouts, and supervisors, and a process
+    /// may exit with `137` on its own.  Check the kernel log or the container's status to confirm.
+    ///
+    /// This only recognizes the shell's encoding.  [`Code::from_status`] reports a child that was
+    /// killed as the raw signal number, `9`, which can't be told apart from a process exiting
+    /// with `9`, so check an [`ExitStatus`][std::process::ExitStatus] with
+    /// [`UnixExitKind::is_oom_likely`] instead.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// assert!(proc_exit::bash::SIGKILL.is_oom_likely());
+    /// assert!(!proc_exit::bash::SIGTERM.is_oom_likely());
+    /// assert_eq!(
+    ///     proc_exit::bash::SIGKILL.describe(),
+    ///     Some("likely killed by OOM killer (SIGKILL)")
+    /// );
+    /// ```
+    #[inline]
+    pub const fn is_oom_likely(self) -> bool {
+        self.as_raw() == crate::bash::SIGKILL.as_raw()
+    }
+
+    /// Determines if the process was forcibly terminated by another, as far as can be told
+    ///
+    /// Windows has no signals and a process ended with `TerminateProcess` (including through a
+    /// job object) exits with whatever code the terminating process chose.  `taskkill /F`, for
+    /// example, uses `1`, which can't be told apart from [`Code::FAILURE`].  What can be
+    /// detected is `STATUS_CONTROL_C_EXIT` (`0xC000013A`), from `Ctrl+C` or `Ctrl+Break`.
+    #[cfg(windows)]
+    #[inline]
+    pub const fn is_terminated_externally(self) -> bool {
+        const STATUS_CONTROL_C_EXIT: u32 = 0xC000013A;
+        self.as_raw() as u32 == STATUS_CONTROL_C_EXIT
+    }
+
+    /// Determines if the code is `0`
+    ///
+    /// This is the same as [`Code::is_ok`], named for symmetry with
+    /// [`Code::is_portable_failure`].
+    #[inline]
+    pub const fn is_portable_success(self) -> bool {
+        self.is_ok()
+    }
+
+    /// Determines if the code is a failure in `1..=255`
+    #[inline]
+    pub const fn is_portable_failure(self) -> bool {
+        self.is_err() && self.is_portable()
+    }
+
+    /// Determines if the code is outside of `0..=255`
+    ///
+    /// Unix will only report the low 8-bits, see [`Code::normalize`].
+    #[inline]
+    pub const fn is_nonportable(self) -> bool {
+        !self.is_portable()
+    }
+
     /// Test if provided exit code is portable across platforms.
     ///
     /// While Windows has wider types for return codes, Unix OS's tend to only support 8-bits,
@@ -129,8 +845,67 @@ impl Code {
     pub const fn is_portable(self) -> bool {
         0 <= self.as_raw() && self.as_raw() <= 255
     }
+
+    /// Interpret the code the way a Unix shell reports it in `$?`
+    ///
+    /// | Code         | Kind |
+    /// |--------------|------|
+    /// | `0..=128`    | [`UnixExitKind::Exited`] with the code |
+    /// | `129..=255`  | [`UnixExitKind::Signaled`] with `code - 128`, like `bash`, `zsh`, and `dash` |
+    /// | `257..=383`  | [`UnixExitKind::Signaled`] with `code - 256`, like `ksh93` |
+    /// | otherwise    | [`UnixExitKind::Unknown`] |
+    ///
+    /// `129..=255` is ambiguous as a program may exit with those codes itself.  Shells don't report
+    /// core dumps in the code, so `core_dumped` is always `false`; see
+    /// [`UnixExitKind::from_status`] for that.  This assumes the code came from a shell, not
+    /// [`Code::from_status`] which reports the signal number itself.
+    ///
+    /// This can be called on any platform.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{Code, UnixExitKind};
+    ///
+    /// assert_eq!(Code::new(3).decode_unix(), UnixExitKind::Exited(3));
+    /// assert_eq!(
+    ///     proc_exit::bash::SIGKILL.decode_unix(),
+    ///     UnixExitKind::Signaled { signal: 9, core_dumped: false }
+    /// );
+    /// assert_eq!(Code::new(-1).decode_unix(), UnixExitKind::Unknown(-1));
+    /// ```
+    pub const fn decode_unix(self) -> UnixExitKind {
+        let raw = self.as_raw();
+        match raw {
+            0..=128 => UnixExitKind::Exited(raw as u8),
+            129..=255 => UnixExitKind::Signaled {
+                signal: raw - 128,
+                core_dumped: false,
+            },
+            257..=383 => UnixExitKind::Signaled {
+                signal: raw - 256,
+                core_dumped: false,
+            },
+            _ => UnixExitKind::Unknown(raw),
+        }
+    }
 }
 
+/// [`Code::FAILURE`]
+///
+/// A fallback can't be told apart from a deliberate `Code::FAILURE`.  To prefer chosen codes
+/// when merging, keep them as `Option<Code>`, like from [`Code::try_from_status`], and only
+/// fall back with `unwrap_or_default` at the end.
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::Code;
+///
+/// let codes = [None, Some(proc_exit::bash::USAGE)];
+/// let chosen = codes.into_iter().flatten().next();
+/// assert_eq!(chosen.unwrap_or_default(), proc_exit::bash::USAGE);
+/// ```
 impl Default for Code {
     #[inline]
     fn default() -> Self {
@@ -139,14 +914,84 @@ impl Default for Code {
     }
 }
 
+/// Formats the [unsigned][Code::as_unsigned] value, the convention for Windows codes
+///
+/// Example:
+///
+/// ```
+/// // STATUS_ACCESS_VIOLATION
+/// let code = proc_exit::Code::new(0xC0000005_u32 as i32);
+/// assert_eq!(format!("{code:#X}"), "0xC0000005");
+/// ```
+impl std::fmt::UpperHex for Code {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::UpperHex::fmt(&self.as_unsigned(), f)
+    }
+}
+
+/// Formats the [unsigned][Code::as_unsigned] value, see [`Code`'s `UpperHex`][Code#impl-UpperHex-for-Code]
+impl std::fmt::LowerHex for Code {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&self.as_unsigned(), f)
+    }
+}
+
+/// Formats the [unsigned][Code::as_unsigned] value
+///
+/// Example:
+///
+/// ```
+/// assert_eq!(format!("{:#o}", proc_exit::bash::NOT_FOUND), "0o177");
+/// ```
+impl std::fmt::Octal for Code {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Octal::fmt(&self.as_unsigned(), f)
+    }
+}
+
 /// Converts an `i32` primitive integer to an exit code.
 impl From<i32> for Code {
     #[inline]
     fn from(n: i32) -> Self {
-        Self(n)
+        Self::new(n)
     }
 }
 
+/// Converts a portable value to an exit code.
+impl From<u8> for Code {
+    #[inline]
+    fn from(n: u8) -> Self {
+        Self::new(n.into())
+    }
+}
+
+macro_rules! impl_try_from {
+    ($($ty:ty),*) => {
+        $(
+            /// Converts an integer to an exit code, if it fits in an `i32`.
+            impl TryFrom<$ty> for Code {
+                type Error = CodeRangeError;
+
+                #[inline]
+                fn try_from(n: $ty) -> Result<Self, Self::Error> {
+                    i32::try_from(n).map(Self::new).map_err(|_| {
+                        CodeRangeError::new(
+                            n as i128,
+                            i32::MIN,
+                            i32::MAX,
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(i64, u32, u64, isize, usize);
+
 /// Converts [`std::process::ExitStatus`] to an exit code by looking at its
 /// [`ExitStatus::code()`] value.
 ///
@@ -160,16 +1005,233 @@ impl From<i32> for Code {
 impl From<std::process::ExitStatus> for Code {
     #[inline]
     fn from(status: std::process::ExitStatus) -> Self {
-        let n = platform_exit_code(status).unwrap_or(Code::default().0);
-        From::from(n)
+        Self::try_from_status(status).unwrap_or_default()
     }
 }
 
+/// How a Unix process ended, see [`Code::decode_unix`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UnixExitKind {
+    /// The process exited with this code
+    Exited(u8),
+    /// The process was terminated by a signal
+    Signaled {
+        /// The signal number, see [`bash::signal_code`][crate::bash::signal_code]
+        signal: i32,
+        /// Whether a core dump was produced
+        core_dumped: bool,
+    },
+    /// The code doesn't follow a known encoding
+    Unknown(i32),
+}
+
+impl UnixExitKind {
+    /// How a child process ended, including whether it dumped core
+    ///
+    /// Returns `None` for stopped and continued processes.
+    #[cfg(unix)]
+    pub fn from_status(status: std::process::ExitStatus) -> Option<Self> {
+        use std::os::unix::process::ExitStatusExt as _;
+
+        if let Some(code) = status.code() {
+            Some(Self::Exited(code as u8))
+        } else {
+            status.signal().map(|signal| Self::Signaled {
+                signal,
+                core_dumped: status.core_dumped(),
+            })
+        }
+    }
+
+    /// Determines if the process was likely killed for running out of memory
+    ///
+    /// This is the same heuristic as [`Code::is_oom_likely`], recognizing both a process killed
+    /// by `SIGKILL` and one that exited with [`bash::SIGKILL`][crate::bash::SIGKILL], like a
+    /// shell whose child was killed.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::UnixExitKind;
+    ///
+    /// # #[cfg(target_os = "linux")] {
+    /// use std::os::unix::process::ExitStatusExt as _;
+    /// use std::process::ExitStatus;
+    ///
+    /// // `WIFSIGNALED`, with `SIGKILL`
+    /// let status = ExitStatus::from_raw(9);
+    /// assert!(!proc_exit::Code::from_status(status).is_oom_likely());
+    /// assert!(UnixExitKind::from_status(status).unwrap().is_oom_likely());
+    /// # }
+    /// assert!(proc_exit::bash::SIGKILL.decode_unix().is_oom_likely());
+    /// assert!(UnixExitKind::Exited(137).is_oom_likely());
+    /// assert!(!UnixExitKind::Exited(9).is_oom_likely());
+    /// ```
+    #[inline]
+    pub const fn is_oom_likely(self) -> bool {
+        const SIGKILL: i32 = crate::bash::SIGKILL.as_raw() - 128;
+        match self {
+            Self::Exited(code) => code as i32 == crate::bash::SIGKILL.as_raw(),
+            Self::Signaled { signal, .. } => signal == SIGKILL,
+            Self::Unknown(_) => false,
+        }
+    }
+}
+
+/// Preferred naming when a [`Code`] has more than one symbolic name
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Convention {
+    /// Prefer generic and [`bash`][crate::bash] names
+    #[default]
+    Bash,
+    /// Prefer [`sysexits`][crate::sysexits] names
+    Sysexits,
+}
+
+/// Broad classification of a [`Code`], see [`Code::category`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CodeCategory {
+    /// [`Code::SUCCESS`]
+    Success,
+    /// [`Code::FAILURE`]
+    GenericFailure,
+    /// The program was called incorrectly, like [`bash::USAGE`][crate::bash::USAGE] or
+    /// [`sysexits::USAGE_ERR`][crate::sysexits::USAGE_ERR]
+    Usage,
+    /// One of the other [`sysexits`][crate::sysexits] codes
+    Sysexit,
+    /// The process was terminated by a signal, like [`bash::SIGINT`][crate::bash::SIGINT]
+    Signal,
+    /// The shell reported an error of its own, like [`bash::NOT_FOUND`][crate::bash::NOT_FOUND]
+    ShellReserved,
+    /// An application-specific code
+    Other,
+}
+
+impl std::fmt::Display for CodeCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Success => "success",
+            Self::GenericFailure => "failure",
+            Self::Usage => "usage error",
+            Self::Sysexit => "sysexits error",
+            Self::Signal => "terminated by signal",
+            Self::ShellReserved => "shell error",
+            Self::Other => "application error",
+        }
+        .fmt(f)
+    }
+}
+
+/// Parses a decimal integer or a symbolic name
+///
+/// Accepted names are those reported by [`Code::name`], matched case-sensitively, including
+/// all aliases (e.g. both `SUCCESS` and `EX_OK`).
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::Code;
+///
+/// assert_eq!("2".parse::<Code>().unwrap(), Code::new(2));
+/// assert_eq!("SIGINT".parse::<Code>().unwrap(), proc_exit::bash::SIGINT);
+/// assert_eq!("EX_USAGE".parse::<Code>().unwrap(), proc_exit::sysexits::USAGE_ERR);
+/// assert!("NOPE".parse::<Code>().is_err());
+/// ```
+impl std::str::FromStr for Code {
+    type Err = ParseCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(entry) = crate::name::lookup_name(s) {
+            return Ok(entry.code);
+        }
+        match s.parse::<i32>() {
+            Ok(n) => Ok(Self::new(n)),
+            Err(err) => {
+                let kind = match err.kind() {
+                    std::num::IntErrorKind::Empty => ParseCodeErrorKind::Empty,
+                    std::num::IntErrorKind::PosOverflow | std::num::IntErrorKind::NegOverflow => {
+                        ParseCodeErrorKind::OutOfRange
+                    }
+                    _ => ParseCodeErrorKind::UnknownName,
+                };
+                Err(ParseCodeError {
+                    input: s.to_owned(),
+                    kind,
+                })
+            }
+        }
+    }
+}
+
+/// Failed to parse a [`Code`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCodeError {
+    input: String,
+    kind: ParseCodeErrorKind,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ParseCodeErrorKind {
+    Empty,
+    OutOfRange,
+    UnknownName,
+}
+
+impl std::fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ParseCodeErrorKind::Empty => "cannot parse exit code from empty string".fmt(f),
+            ParseCodeErrorKind::OutOfRange => {
+                write!(f, "exit code `{}` is out of range", self.input)
+            }
+            ParseCodeErrorKind::UnknownName => write!(
+                f,
+                "unknown exit code `{}`, expected an integer or a name like `SIGINT` or `EX_USAGE`",
+                self.input
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseCodeError {}
+
+/// An integer could not be represented as a [`Code`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CodeRangeError {
+    value: i128,
+    min: i32,
+    max: i32,
+}
+
+impl CodeRangeError {
+    pub(crate) const fn new(value: i128, min: i32, max: i32) -> Self {
+        Self { value, min, max }
+    }
+}
+
+impl std::fmt::Display for CodeRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "exit code {} out of range {}..={}",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+impl std::error::Error for CodeRangeError {}
+
 #[cfg(target_family = "unix")]
 #[inline]
 fn platform_exit_code(status: std::process::ExitStatus) -> Option<i32> {
     use std::os::unix::process::ExitStatusExt;
-    status.code().or_else(|| status.signal())
+    status
+        .code()
+        .or_else(|| status.signal())
+        .or_else(|| status.stopped_signal())
 }
 
 #[cfg(not(target_family = "unix"))]
@@ -178,6 +1240,20 @@ fn platform_exit_code(status: std::process::ExitStatus) -> Option<i32> {
     status.code()
 }
 
+#[cfg(target_family = "unix")]
+#[inline]
+const fn platform_normalize(raw: i32) -> i32 {
+    raw & 0xff
+}
+
+#[cfg(not(target_family = "unix"))]
+#[inline]
+const fn platform_normalize(raw: i32) -> i32 {
+    raw
+}
+
+/// Non-portable codes are reported as [`Code::FAILURE`], even on Windows, see
+/// [`Code::as_exit_code`]
 impl std::process::Termination for Code {
     #[inline]
     fn report(self) -> std::process::ExitCode {
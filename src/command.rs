@@ -0,0 +1,229 @@
+/// Extension for running a [`std::process::Command`] in terms of [`Code`][crate::Code]s
+pub trait CommandExt {
+    /// Run the command, checking that it exits with `code`
+    ///
+    /// On mismatch, this fails with [`Code::FAILURE`][crate::Code::FAILURE] and a message with
+    /// the expected and actual codes.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let result = std::process::Command::new("false").expect_code(proc_exit::Code::FAILURE);
+    /// assert!(result.is_ok());
+    ///
+    /// let err = std::process::Command::new("true")
+    ///     .expect_code(proc_exit::bash::USAGE)
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "`true` exited with 0 (SUCCESS), expected 2 (USAGE)");
+    /// ```
+    fn expect_code(&mut self, code: crate::Code) -> crate::ExitResult;
+
+    /// Run the command, capturing its output, and fail if it is unsuccessful
+    ///
+    /// On failure, the [`Exit`][crate::Exit] has the command's code, see
+    /// [`Code::from_status`][crate::Code::from_status].  The messages name the program and include
+    /// the captured `stderr`, truncated to `max_stderr_bytes`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let output = std::process::Command::new("echo").arg("hello").output_ok(1024).unwrap();
+    /// assert_eq!(output.stdout, b"hello\n");
+    ///
+    /// let err = std::process::Command::new("sh")
+    ///     .args(["-c", "echo oops >&2; exit 3"])
+    ///     .output_ok(1024)
+    ///     .unwrap_err();
+    /// assert_eq!(err.code(), proc_exit::Code::new(3));
+    /// assert_eq!(err.to_string(), "`sh` failed\noops");
+    ///
+    /// // The `…` counts towards the limit
+    /// let err = std::process::Command::new("sh")
+    ///     .args(["-c", "echo 'oh no!' >&2; exit 3"])
+    ///     .output_ok(5)
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "`sh` failed\noh…");
+    /// ```
+    fn output_ok(&mut self, max_stderr_bytes: usize) -> Result<std::process::Output, crate::Exit>;
+
+    /// Run the command, capturing the end of its `stderr`, and fail if it is unsuccessful
+    ///
+    /// `stdin` and `stdout` are inherited like with
+    /// [`Command::status`][std::process::Command::status].  On failure, the
+    /// [`Exit`][crate::Exit] has the command's code, see
+    /// [`Code::from_status`][crate::Code::from_status].  The messages name the program and
+    /// include the last `tail_lines` lines of `stderr`.
+    ///
+    /// `stderr` is read as it is written, keeping only the last `tail_lines` lines, so a chatty
+    /// command doesn't grow memory use.  Each kept line is held in full.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let err = std::process::Command::new("sh")
+    ///     .args(["-c", "echo one >&2; echo two >&2; echo three >&2; exit 3"])
+    ///     .status_ok_with_stderr(2)
+    ///     .unwrap_err();
+    /// assert_eq!(err.code(), proc_exit::Code::new(3));
+    /// assert_eq!(err.to_string(), "`sh` failed\ntwo\nthree");
+    /// ```
+    fn status_ok_with_stderr(&mut self, tail_lines: usize) -> crate::ExitResult;
+}
+
+impl CommandExt for std::process::Command {
+    fn expect_code(&mut self, expected: crate::Code) -> crate::ExitResult {
+        let program = program(self);
+        let status = self.status().map_err(|err| spawn_failed(&program, err))?;
+        let actual = crate::Code::from_status(status);
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(crate::Code::FAILURE.with_message(format!(
+                "`{program}` exited with {}, expected {}",
+                Named(actual),
+                Named(expected)
+            )))
+        }
+    }
+
+    fn output_ok(&mut self, max_stderr_bytes: usize) -> Result<std::process::Output, crate::Exit> {
+        let program = program(self);
+        let output = self.output().map_err(|err| spawn_failed(&program, err))?;
+        let code = crate::Code::from_status(output.status);
+        if code.is_ok() {
+            return Ok(output);
+        }
+        let mut exit = code.with_message(format!("`{program}` failed"));
+        let mut stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        stderr.truncate(stderr.trim_end().len());
+        crate::reporter::truncate(&mut stderr, max_stderr_bytes);
+        if !stderr.is_empty() {
+            exit = exit.add_message(stderr);
+        }
+        Err(exit)
+    }
+
+    fn status_ok_with_stderr(&mut self, tail_lines: usize) -> crate::ExitResult {
+        let program = program(self);
+        let mut child = self
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|err| spawn_failed(&program, err))?;
+        let tail = match child.stderr.take() {
+            Some(stderr) => read_tail(stderr, tail_lines),
+            None => Ok(String::new()),
+        };
+        let status = child.wait().map_err(|err| spawn_failed(&program, err))?;
+        let code = crate::Code::from_status(status);
+        if code.is_ok() {
+            return Ok(());
+        }
+        let mut exit = code.with_message(format!("`{program}` failed"));
+        match tail {
+            Ok(tail) if !tail.trim().is_empty() => exit = exit.add_message(tail),
+            Ok(_) => {}
+            Err(err) => exit = exit.add_message(format!("failed to read `stderr`: {err}")),
+        }
+        Err(exit)
+    }
+}
+
+fn read_tail(stderr: impl std::io::Read, tail_lines: usize) -> std::io::Result<String> {
+    use std::io::BufRead as _;
+
+    let mut stderr = std::io::BufReader::new(stderr);
+    let mut tail = std::collections::VecDeque::with_capacity(tail_lines);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if stderr.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        if tail_lines == 0 {
+            continue;
+        }
+        if tail.len() == tail_lines {
+            tail.pop_front();
+        }
+        let text = String::from_utf8_lossy(&line);
+        tail.push_back(text.trim_end_matches(['\n', '\r']).to_owned());
+    }
+    Ok(Vec::from(tail).join("\n"))
+}
+
+/// Run `cmd` in place of the current process, exiting with its code
+///
+/// This is for programs that re-run themselves, like after a self-update, where the caller
+/// should see the new process's code as if it had been run directly.
+///
+/// On Unix, the current process is replaced with [`exec`][std::os::unix::process::CommandExt::exec].
+/// The PID, and with it signals and job control, carry over to `cmd` and the parent receives
+/// `cmd`'s status unchanged, including death by a signal.  Destructors don't run, so `stdout` is
+/// flushed first and anything else buffered is lost.
+///
+/// Elsewhere, `cmd` is run as a child process and waited on, exiting with its code converted
+/// by [`Code::from_status`][crate::Code::from_status].  The current process stays alive
+/// meanwhile, holding its resources.
+///
+/// If `cmd` can't be run, this [reports][crate::report] the error and exits like a shell would,
+/// with [`bash::NOT_FOUND`][crate::bash::NOT_FOUND] if the program doesn't exist and
+/// [`bash::NOT_EXECUTABLE`][crate::bash::NOT_EXECUTABLE] otherwise.
+///
+/// Example:
+///
+/// ```no_run
+/// let exe = std::env::current_exe().unwrap();
+/// let mut cmd = std::process::Command::new(exe);
+/// cmd.args(std::env::args_os().skip(1));
+/// proc_exit::reexec(&mut cmd);
+/// ```
+pub fn reexec(cmd: &mut std::process::Command) -> ! {
+    let program = program(cmd);
+    #[cfg(unix)]
+    let err = {
+        use std::os::unix::process::CommandExt as _;
+
+        // Buffered output is lost on `exec`, but failing to flush it is no reason not to run `cmd`
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+        cmd.exec()
+    };
+    #[cfg(not(unix))]
+    let err = match cmd.status() {
+        Ok(status) => crate::Code::from_status(status).process_exit(),
+        Err(err) => err,
+    };
+    let code = if err.kind() == std::io::ErrorKind::NotFound {
+        crate::bash::NOT_FOUND
+    } else {
+        crate::bash::NOT_EXECUTABLE
+    };
+    crate::exit(Err(
+        code.with_message(format!("failed to run `{program}`: {err}"))
+    ))
+}
+
+fn program(cmd: &std::process::Command) -> String {
+    cmd.get_program().to_string_lossy().into_owned()
+}
+
+fn spawn_failed(program: &str, err: std::io::Error) -> crate::Exit {
+    crate::Code::FAILURE.with_message(format!("failed to run `{program}`: {err}"))
+}
+
+struct Named(crate::Code);
+
+impl std::fmt::Display for Named {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.as_raw())?;
+        if let Some(name) = self.0.name() {
+            write!(f, " ({name})")?;
+        }
+        Ok(())
+    }
+}
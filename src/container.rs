@@ -0,0 +1,22 @@
+//! Container runtime exit codes, like `docker run` and `podman run`
+//!
+//! For tools that wrap container runtimes.  Any other code is the container's own.
+//!
+//! **Note:** these overlap with [`bash`][crate::bash] codes, so a code can't tell whether the
+//! runtime or the containerized command reported it:
+//! - [`NOT_EXECUTABLE`] is [`bash::NOT_EXECUTABLE`][crate::bash::NOT_EXECUTABLE]
+//! - [`NOT_FOUND`] is [`bash::NOT_FOUND`][crate::bash::NOT_FOUND]
+//!
+//! [`Code::name`][crate::Code::name] and friends report the `bash` names for these.
+//!
+//! See the [`docker run` reference](https://docs.docker.com/reference/cli/docker/container/run/#exit-status)
+//! and [`podman run`](https://docs.podman.io/en/latest/markdown/podman-run.1.html#exit-status).
+
+/// The runtime itself failed, like an invalid flag or the daemon being unavailable
+pub const RUNTIME_ERROR: crate::Code = crate::Code::new(125);
+
+/// The contained command can't be invoked, like lacking permission to execute it
+pub const NOT_EXECUTABLE: crate::Code = crate::Code::new(126);
+
+/// The contained command can't be found
+pub const NOT_FOUND: crate::Code = crate::Code::new(127);
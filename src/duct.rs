@@ -0,0 +1,36 @@
+//! Run [`duct`](https://docs.rs/duct) expressions in terms of [`Code`][crate::Code]s
+//!
+//! Example:
+//!
+//! ```
+//! use proc_exit::prelude::*;
+//!
+//! fn run() -> proc_exit::ExitResult {
+//!     duct::cmd!("echo", "hello")
+//!         .pipe(duct::cmd!("sh", "-c", "cat >/dev/null; exit 3"))
+//!         .exit_ok()?;
+//!     proc_exit::Code::SUCCESS.ok()
+//! }
+//!
+//! assert_eq!(run().unwrap_err().code(), proc_exit::Code::new(3));
+//! ```
+
+/// Extension for running a [`duct::Expression`]
+pub trait DuctExt {
+    /// Run the expression, passing along its code on failure
+    ///
+    /// `duct` normally reports a non-zero status as an [`std::io::Error`], losing the code.  This
+    /// uses the status instead, see [`Code::from_status`][crate::Code::from_status].  Failing to
+    /// run the expression is reported with
+    /// [`ToSysexitsResultExt`][crate::sysexits::ToSysexitsResultExt].
+    fn exit_ok(&self) -> crate::ExitResult;
+}
+
+impl DuctExt for duct::Expression {
+    fn exit_ok(&self) -> crate::ExitResult {
+        use crate::sysexits::ToSysexitsResultExt as _;
+
+        let output = self.unchecked().run().to_sysexits()?;
+        crate::Code::from_status(output.status).ok()
+    }
+}
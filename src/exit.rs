@@ -1,58 +1,649 @@
 use std::io::Write;
+use std::rc::Rc;
 
 /// For use in `fn run() -> ExitResult {}`
 pub type ExitResult = Result<(), Exit>;
 
 /// Error type for exiting programs.
+///
+/// `Exit` is kept small, as it is moved around in every [`ExitResult`].  Creating and reporting
+/// an `Exit` without a message does not allocate, nor does reporting success or a message that
+/// is a `&'static str`.  Any other message is boxed, so a single message is one allocation.
+/// Everything else, like more messages, a hint, or a source, is boxed as well and shares one
+/// more allocation.  With the `backtrace` feature, a [backtrace][Exit::backtrace] is captured
+/// once a message or source is attached, which walks the stack and allocates when enabled.
+/// An `Exit` without either never captures one.
 pub struct Exit {
     code: crate::Code,
-    msg: Option<Box<dyn std::fmt::Display>>,
+    msg: Option<Message>,
+    /// Rarely used, so kept out of line
+    extra: Option<Box<Extra>>,
+}
+
+#[derive(Default)]
+struct Extra {
+    /// Messages added after `msg`, only non-empty when `msg` is set
+    more_msgs: Vec<Message>,
+    hint: Option<Box<dyn std::fmt::Display>>,
+    app_code: Option<Box<dyn std::fmt::Display>>,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    /// Whether `source`'s `Display` is already the message, so only its causes are new
+    source_is_message: bool,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<std::backtrace::Backtrace>,
 }
 
 impl Exit {
     #[inline]
     pub fn new(code: crate::Code) -> Self {
-        Self { code, msg: None }
+        Self {
+            code,
+            msg: None,
+            extra: None,
+        }
+    }
+
+    /// Keep a backtrace, if enabled and not already captured, without allocating `extra`
+    /// otherwise
+    #[inline]
+    fn capture_backtrace(&mut self) {
+        #[cfg(feature = "backtrace")]
+        {
+            if self
+                .extra
+                .as_ref()
+                .is_some_and(|extra| extra.backtrace.is_some())
+            {
+                return;
+            }
+            let backtrace = std::backtrace::Backtrace::capture();
+            if backtrace.status() == std::backtrace::BacktraceStatus::Captured {
+                self.extra_mut().backtrace = Some(backtrace);
+            }
+        }
     }
 
     #[inline]
     pub fn with_message<D: std::fmt::Display + 'static>(mut self, msg: D) -> Self {
-        self.msg = Some(Box::new(msg));
+        self.msg = Some(Message::new(msg));
+        if let Some(extra) = self.extra.as_mut() {
+            extra.more_msgs.clear();
+            extra.source_is_message = false;
+        }
+        self.capture_backtrace();
+        self
+    }
+
+    /// Set a message that is rendered in the user's locale when reported
+    ///
+    /// Unlike [`Exit::with_message`], nothing is rendered now.  `message` is called with the
+    /// locale each time the `Exit` is displayed, so a locale chosen after the failure, e.g. from
+    /// a `--lang` flag parsed later, still applies.  The locale is the one set with
+    /// [`Reporter::locale`][crate::Reporter::locale] or, otherwise, the first non-empty of the
+    /// `LC_ALL`, `LC_MESSAGES`, and `LANG` environment variables, falling back to `C`.  It is
+    /// passed through as-is, like `fr_FR.UTF-8`, for the application's own translation
+    /// catalog to interpret.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::sysexits::NO_INPUT.with_message_fn(|locale| {
+    ///     if locale.starts_with("fr") {
+    ///         "fichier introuvable".to_owned()
+    ///     } else {
+    ///         "file not found".to_owned()
+    ///     }
+    /// });
+    /// let code = proc_exit::Reporter::new().locale("fr_FR.UTF-8").report(Err(exit));
+    /// assert_eq!(code, proc_exit::sysexits::NO_INPUT);
+    /// ```
+    #[inline]
+    pub fn with_message_fn<F: Fn(&str) -> String + 'static>(self, message: F) -> Self {
+        self.with_message(crate::locale::LocalizedMessage(message))
+    }
+
+    /// Create an `Exit` with a boxed error as the message
+    ///
+    /// The error must be `'static` as it is kept until reported.
+    ///
+    /// [`WithCodeResultExt::with_code`] also works with `Result<T, Box<dyn Error>>`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// fn load() -> Result<(), Box<dyn std::error::Error>> {
+    ///     Err("config not found".into())
+    /// }
+    ///
+    /// let exit = proc_exit::Exit::from_boxed_error(load().unwrap_err(), proc_exit::sysexits::CONFIG_ERR);
+    /// assert_eq!(exit.to_string(), "config not found");
+    ///
+    /// let result = load().with_code(proc_exit::sysexits::CONFIG_ERR);
+    /// assert!(result.is_err());
+    /// ```
+    #[inline]
+    pub fn from_boxed_error(err: Box<dyn std::error::Error + 'static>, code: crate::Code) -> Self {
+        Self::new(code).with_message(err)
+    }
+
+    /// The code to exit with
+    #[inline]
+    pub fn code(&self) -> crate::Code {
+        self.code
+    }
+
+    /// Append another user-visible message, keeping the existing ones
+    ///
+    /// Messages are reported in the order they were added, each on its own line.  The code is
+    /// left as-is; when accumulating failures, the caller decides which code the batch exits
+    /// with (e.g. the first or the worst) and creates the `Exit` with it.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE
+    ///     .with_message("a.txt: not found")
+    ///     .add_message("b.txt: permission denied");
+    /// assert_eq!(exit.to_string(), "a.txt: not found\nb.txt: permission denied");
+    /// ```
+    #[inline]
+    pub fn add_message<D: std::fmt::Display + 'static>(mut self, msg: D) -> Self {
+        self.push_message(Message::new(msg));
+        self
+    }
+
+    /// Combine with another failure, like one from cleaning up after this one
+    ///
+    /// The code is the [worst][crate::Code::worst] of the two, preferring `self`'s when they are
+    /// equally severe.  `self`'s messages come first, followed by `other`'s.  `self`'s hint is
+    /// kept, if it has one.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE
+    ///     .with_message("build failed")
+    ///     .and(proc_exit::sysexits::IO_ERR.with_message("could not remove `target/tmp`"));
+    /// assert_eq!(exit.code(), proc_exit::Code::FAILURE);
+    /// assert_eq!(exit.to_string(), "build failed\ncould not remove `target/tmp`");
+    ///
+    /// let exit = proc_exit::Code::FAILURE
+    ///     .as_exit()
+    ///     .and(proc_exit::bash::SIGKILL.as_exit());
+    /// assert_eq!(exit.code(), proc_exit::bash::SIGKILL);
+    /// ```
+    pub fn and(mut self, mut other: Exit) -> Self {
+        self.code = self.code.worst(other.code);
+        self.append_messages(&mut other);
+        if self.hint().is_none() {
+            if let Some(hint) = other.extra.and_then(|extra| extra.hint) {
+                self.extra_mut().hint = Some(hint);
+            }
+        }
+        self
+    }
+
+    /// Suggest how the user might resolve the failure
+    ///
+    /// The hint is kept separate from the messages and is reported after them, on its own line,
+    /// prefixed with `hint: `.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::bash::USAGE
+    ///     .with_message("no input files")
+    ///     .with_hint("try running with `--help`");
+    /// let mut buffer = Vec::new();
+    /// exit.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"no input files\nhint: try running with `--help`\n");
+    /// ```
+    #[inline]
+    pub fn with_hint<D: std::fmt::Display + 'static>(mut self, hint: D) -> Self {
+        self.extra_mut().hint = Some(Box::new(hint));
+        self
+    }
+
+    /// The suggestion for resolving the failure, see [`Exit::with_hint`]
+    #[inline]
+    pub fn hint(&self) -> Option<&dyn std::fmt::Display> {
+        self.extra.as_ref()?.hint.as_deref()
+    }
+
+    /// Identify the failure with an application-specific error code, like `E1234`
+    ///
+    /// This is separate from the process [`Code`][crate::Code], which might just be
+    /// [`Code::FAILURE`][crate::Code::FAILURE], and is meant for looking up documentation or
+    /// support.  It is reported before the first message, like `error[E1234]: message`, and not
+    /// at all without a message.  `Display` only renders the messages.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE
+    ///     .with_message("lockfile is corrupt")
+    ///     .with_app_code("E1234");
+    /// assert_eq!(exit.app_code().unwrap().to_string(), "E1234");
+    ///
+    /// let mut buffer = Vec::new();
+    /// exit.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"error[E1234]: lockfile is corrupt\n");
+    /// ```
+    #[inline]
+    pub fn with_app_code<D: std::fmt::Display + 'static>(mut self, app_code: D) -> Self {
+        self.extra_mut().app_code = Some(Box::new(app_code));
+        self
+    }
+
+    /// The application-specific error code, see [`Exit::with_app_code`]
+    #[inline]
+    pub fn app_code(&self) -> Option<&dyn std::fmt::Display> {
+        self.extra.as_ref()?.app_code.as_deref()
+    }
+
+    /// Keep the error behind the message for introspection
+    ///
+    /// The message is unchanged.  [`Reporter`][crate::Reporter] lists the error and its
+    /// [causes][std::error::Error::source] in [`Verbosity::Verbose`][crate::Verbosity::Verbose].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let err = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+    /// let exit = proc_exit::sysexits::IO_ERR
+    ///     .with_message("failed to save")
+    ///     .with_source(err);
+    /// assert_eq!(exit.to_string(), "failed to save");
+    /// assert_eq!(exit.source().unwrap().to_string(), "disk full");
+    /// ```
+    #[inline]
+    pub fn with_source<E: std::error::Error + Send + Sync + 'static>(mut self, source: E) -> Self {
+        let extra = self.extra_mut();
+        extra.source = Some(Box::new(source));
+        extra.source_is_message = false;
+        self.capture_backtrace();
+        self
+    }
+
+    /// Keep `source` when its `Display` was used as the message
+    #[inline]
+    pub(crate) fn with_message_source(
+        mut self,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    ) -> Self {
+        let extra = self.extra_mut();
+        extra.source = Some(source);
+        extra.source_is_message = true;
+        self.capture_backtrace();
+        self
+    }
+
+    /// The first error not already shown as the message
+    pub(crate) fn first_cause(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let extra = self.extra.as_deref()?;
+        let source: &(dyn std::error::Error + 'static) = extra.source.as_deref()?;
+        if extra.source_is_message {
+            source.source()
+        } else {
+            Some(source)
+        }
+    }
+
+    /// The error behind the message, see [`Exit::with_source`]
+    #[inline]
+    pub fn source(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        self.extra.as_ref()?.source.as_deref()
+    }
+
+    /// Where the `Exit`'s first message or source was attached
+    ///
+    /// Only captured when enabled with `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`, see
+    /// [`Backtrace::capture`][std::backtrace::Backtrace::capture].  It isn't captured by
+    /// [`Exit::new`], so an `Exit` without a message, like from [`Code::ok`][crate::Code::ok]
+    /// or one only carrying a code through [`collect`], stays cheap.
+    /// [`Reporter`][crate::Reporter] prints it in [`Verbosity::Verbose`][crate::Verbosity::Verbose].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::sysexits::SOFTWARE_ERR.with_message("invariant violated");
+    /// if let Some(backtrace) = exit.backtrace() {
+    ///     println!("{backtrace}");
+    /// }
+    /// ```
+    #[cfg(feature = "backtrace")]
+    #[inline]
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.extra.as_ref()?.backtrace.as_ref()
+    }
+
+    /// Wrap the user-visible message with additional context
+    ///
+    /// The context is rendered as a prefix, separated from the existing message by `: `,
+    /// e.g. `while doing thing: permission denied`.  Each layer adds its prefix in front of the
+    /// last.  If there is no message yet, the context becomes the message.  With multiple
+    /// messages, each is prefixed.
+    ///
+    /// See [`Exit::with_message`] to replace the message instead.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE
+    ///     .with_message("permission denied")
+    ///     .context("while doing thing");
+    /// assert_eq!(exit.to_string(), "while doing thing: permission denied");
+    ///
+    /// let exit = proc_exit::Code::FAILURE
+    ///     .with_message("a.txt: not found")
+    ///     .add_message("b.txt: not found")
+    ///     .context("while loading");
+    /// assert_eq!(
+    ///     exit.to_string(),
+    ///     "while loading: a.txt: not found\nwhile loading: b.txt: not found"
+    /// );
+    /// ```
+    #[inline]
+    pub fn context<D: std::fmt::Display + 'static>(mut self, context: D) -> Self {
+        match self.msg.take() {
+            None => {
+                self.msg = Some(Message::new(context));
+                self.capture_backtrace();
+            }
+            Some(msg) => {
+                let context: Rc<dyn std::fmt::Display> = Rc::new(context);
+                let wrap = |msg| {
+                    Message::Boxed(Box::new(Context {
+                        context: context.clone(),
+                        msg,
+                    }))
+                };
+                self.msg = Some(wrap(msg));
+                if let Some(extra) = self.extra.as_mut() {
+                    extra.more_msgs = std::mem::take(&mut extra.more_msgs)
+                        .into_iter()
+                        .map(wrap)
+                        .collect();
+                }
+            }
+        }
         self
     }
+
+    /// Report the messages to `stderr` now, keeping them for later
+    ///
+    /// This lets an inner layer log a failure while an outer layer still owns exiting.  As the
+    /// messages are kept, they will be printed again by [`report`] unless removed with
+    /// [`Exit::suppress_message`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// fn step() -> proc_exit::ExitResult {
+    ///     Err(proc_exit::Code::FAILURE.with_message("step failed").print().suppress_message())
+    /// }
+    /// ```
+    pub fn print(self) -> Self {
+        // The messages are kept for the final report, so nothing is lost if this fails
+        let _ = self.write_to(&mut std::io::stderr().lock());
+        self
+    }
+
+    /// Remove the messages and hint, keeping the code
+    ///
+    /// The `Exit` will then exit silently, like when the failure was already reported.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE.with_message("step failed").suppress_message();
+    /// assert_eq!(exit.to_message_string(), None);
+    /// assert_eq!(exit.code(), proc_exit::Code::FAILURE);
+    /// ```
+    #[inline]
+    pub fn suppress_message(mut self) -> Self {
+        self.msg = None;
+        if let Some(extra) = self.extra.as_mut() {
+            extra.more_msgs.clear();
+            extra.hint = None;
+            extra.source_is_message = false;
+        }
+        self
+    }
+
+    /// Render the user-visible messages, each on its own line, followed by any hint
+    ///
+    /// This is what [`report`] writes to `stderr`, except write errors are returned rather than
+    /// ignored.  Nothing is written when there is no message or hint.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE.with_message("permission denied");
+    /// let mut buffer = Vec::new();
+    /// exit.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"permission denied\n");
+    /// ```
+    pub fn write_to(&self, w: &mut impl Write) -> std::io::Result<()> {
+        for (i, msg) in self.messages().enumerate() {
+            if i == 0 {
+                self.write_app_code_to(w)?;
+            }
+            writeln!(w, "{msg}")?;
+        }
+        self.write_hint_to(w)
+    }
+
+    pub(crate) fn messages(&self) -> impl Iterator<Item = &dyn std::fmt::Display> {
+        let more_msgs = self
+            .extra
+            .as_ref()
+            .map(|extra| extra.more_msgs.as_slice())
+            .unwrap_or_default();
+        self.msg
+            .iter()
+            .chain(more_msgs)
+            .map(|msg| msg as &dyn std::fmt::Display)
+    }
+
+    pub(crate) fn has_message(&self) -> bool {
+        self.msg.is_some()
+    }
+
+    fn extra_mut(&mut self) -> &mut Extra {
+        self.extra.get_or_insert_with(Default::default)
+    }
+
+    fn push_message(&mut self, msg: Message) {
+        if self.msg.is_none() {
+            self.msg = Some(msg);
+            self.capture_backtrace();
+        } else {
+            self.extra_mut().more_msgs.push(msg);
+        }
+    }
+
+    /// Move `other`'s messages after `self`'s
+    fn append_messages(&mut self, other: &mut Exit) {
+        let more_msgs = other
+            .extra
+            .as_mut()
+            .map(|extra| std::mem::take(&mut extra.more_msgs))
+            .unwrap_or_default();
+        for msg in other.msg.take().into_iter().chain(more_msgs) {
+            self.push_message(msg);
+        }
+    }
+
+    pub(crate) fn write_app_code_to(&self, w: &mut impl Write) -> std::io::Result<()> {
+        if let Some(app_code) = self.app_code() {
+            write!(w, "error[{app_code}]: ")?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_hint_to(&self, w: &mut impl Write) -> std::io::Result<()> {
+        if let Some(hint) = self.hint() {
+            writeln!(w, "hint: {hint}")?;
+        }
+        Ok(())
+    }
+
+    /// Report the message and convert to [`ExitCode`][std::process::ExitCode], for
+    /// `fn main() -> ExitCode`
+    ///
+    /// The message is reported like [`report`][crate::report].  In contrast, returning an `Exit`
+    /// from `main` relies on its [`Termination`][std::process::Termination] impl, which only
+    /// maps the code, dropping the message.
+    ///
+    /// Like the `Termination` impl, a non-portable code is returned as
+    /// [`ExitCode::FAILURE`][std::process::ExitCode::FAILURE], even on Windows, as `ExitCode`
+    /// can only be built from a `u8`.  Use [`exit`] to exit with the full value.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// fn run() -> proc_exit::ExitResult {
+    ///     proc_exit::Code::SUCCESS.ok()
+    /// }
+    ///
+    /// fn main() -> std::process::ExitCode {
+    ///     match run() {
+    ///         Ok(()) => std::process::ExitCode::SUCCESS,
+    ///         Err(exit) => exit.report_to_exit_code(),
+    ///     }
+    /// }
+    /// ```
+    pub fn report_to_exit_code(self) -> std::process::ExitCode {
+        std::process::Termination::report(report(Err(self)))
+    }
+
+    /// Render the user-visible messages, if any, joined by newlines
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let exit = proc_exit::Code::FAILURE.with_message("permission denied");
+    /// assert_eq!(exit.to_message_string().as_deref(), Some("permission denied"));
+    /// assert_eq!(proc_exit::Code::FAILURE.as_exit().to_message_string(), None);
+    /// ```
+    pub fn to_message_string(&self) -> Option<String> {
+        if !self.has_message() {
+            None
+        } else {
+            Some(self.to_string())
+        }
+    }
+}
+
+/// A message, only boxed when it isn't a `&'static str`
+enum Message {
+    Static(&'static str),
+    Boxed(Box<dyn std::fmt::Display>),
+}
+
+impl Message {
+    fn new<D: std::fmt::Display + 'static>(msg: D) -> Self {
+        match (&msg as &dyn std::any::Any).downcast_ref::<&'static str>() {
+            Some(msg) => Self::Static(msg),
+            None => Self::Boxed(Box::new(msg)),
+        }
+    }
+}
+
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Static(msg) => msg.fmt(f),
+            Self::Boxed(msg) => msg.fmt(f),
+        }
+    }
+}
+
+struct Context {
+    context: Rc<dyn std::fmt::Display>,
+    msg: Message,
+}
+
+impl std::fmt::Display for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.context, self.msg)
+    }
 }
 
 impl std::fmt::Display for Exit {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(msg) = self.msg.as_ref() {
-            msg.fmt(f)
-        } else {
-            Ok(())
+        for (i, msg) in self.messages().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            msg.fmt(f)?;
         }
+        Ok(())
     }
 }
 
+/// Non-portable codes are reported as [`Code::FAILURE`][crate::Code::FAILURE], even on Windows,
+/// see [`Code::as_exit_code`][crate::Code::as_exit_code]
 impl std::process::Termination for Exit {
     #[inline]
     fn report(self) -> std::process::ExitCode {
-        self.code
-            .as_exit_code()
-            .unwrap_or(std::process::ExitCode::FAILURE)
+        std::process::Termination::report(self.code)
+    }
+}
+
+/// Compares the codes and the rendered messages, e.g. for tests
+///
+/// Messages are compared by rendering them with `Display`, allocating each time.  The hint
+/// and source are not compared.
+///
+/// Example:
+///
+/// ```
+/// fn run() -> proc_exit::ExitResult {
+///     Err(proc_exit::Code::new(2).with_message("bad"))
+/// }
+///
+/// assert_eq!(run(), Err(proc_exit::Code::new(2).with_message("bad")));
+/// assert_ne!(run(), Err(proc_exit::Code::new(2).with_message("good")));
+/// ```
+impl PartialEq for Exit {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.to_string() == other.to_string()
     }
 }
 
+/// For `fn main() -> Result<(), Exit>`, renders the messages like `Display` or, when there are
+/// none, the code
+///
+/// Example:
+///
+/// ```
+/// let exit = proc_exit::bash::USAGE.with_message("missing `--input`");
+/// assert_eq!(format!("{exit:?}"), "missing `--input`");
+///
+/// let exit = proc_exit::bash::USAGE.as_exit();
+/// assert_eq!(format!("{exit:?}"), "exited with code 2");
+/// ```
 impl std::fmt::Debug for Exit {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // For compatibility with `std::process::Termination`
-        std::fmt::Display::fmt(self, f)
+        if self.has_message() {
+            std::fmt::Display::fmt(self, f)
+        } else {
+            write!(f, "exited with code {}", self.code.as_raw())
+        }
     }
 }
 
 /// Extension for converting errors to `Exit`.
 pub trait WithCodeResultExt<T> {
     /// Convert an Error into an `Exit`
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
     fn with_code(self, code: crate::Code) -> Result<T, Exit>;
 }
 
@@ -63,25 +654,499 @@ impl<T, E: std::fmt::Display + 'static> WithCodeResultExt<T> for Result<T, E> {
     }
 }
 
+/// Extension for converting a missing value to `Exit`.
+pub trait WithCodeOptionExt<T> {
+    /// Convert `None` into an `Exit` with a user-visible message
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let value: Option<&str> = None;
+    /// let result = value.or_code(proc_exit::bash::USAGE, "missing config key `name`");
+    /// assert!(result.is_err());
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    fn or_code<D: std::fmt::Display + 'static>(self, code: crate::Code, msg: D) -> Result<T, Exit>;
+}
+
+impl<T> WithCodeOptionExt<T> for Option<T> {
+    #[inline]
+    fn or_code<D: std::fmt::Display + 'static>(self, code: crate::Code, msg: D) -> Result<T, Exit> {
+        self.ok_or_else(|| Exit::new(code).with_message(msg))
+    }
+}
+
+/// Extension for adjusting an [`Exit`] in a [`Result`]
+pub trait ExitResultExt<T> {
+    /// Transform the code of a failure, keeping its messages
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// // Offset a subcommand's codes into a reserved block
+    /// let result = proc_exit::bash::USAGE
+    ///     .ok()
+    ///     .map_err_code(|c| proc_exit::Code::new(c.as_raw() + 100));
+    /// assert_eq!(result.unwrap_err().code(), proc_exit::Code::new(102));
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    fn map_err_code<F: FnOnce(crate::Code) -> crate::Code>(self, f: F) -> Result<T, Exit>;
+}
+
+impl<T> ExitResultExt<T> for Result<T, Exit> {
+    #[inline]
+    fn map_err_code<F: FnOnce(crate::Code) -> crate::Code>(self, f: F) -> Result<T, Exit> {
+        self.map_err(|mut err| {
+            err.code = f(err.code);
+            err
+        })
+    }
+}
+
+/// Extension for reducing any [`Result`] to a [`Code`][crate::Code]
+///
+/// Unlike [`WithCodeResultExt::with_code`], which keeps the error as the [`Exit`]'s message,
+/// these discard the value and the error, for glue code that only needs to know whether it
+/// worked.
+pub trait CodeResultExt<E> {
+    /// [`Code::SUCCESS`][crate::Code::SUCCESS] for `Ok` and `code` for `Err`
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let result: Result<u32, std::num::ParseIntError> = "42".parse();
+    /// assert_eq!(result.code_or(proc_exit::bash::USAGE), Code::SUCCESS);
+    ///
+    /// let result: Result<u32, std::num::ParseIntError> = "forty-two".parse();
+    /// assert_eq!(result.code_or(proc_exit::bash::USAGE), proc_exit::bash::USAGE);
+    /// ```
+    #[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
+    fn code_or(self, code: crate::Code) -> crate::Code;
+
+    /// [`Code::SUCCESS`][crate::Code::SUCCESS] for `Ok` and the code from `f` for `Err`
+    ///
+    /// `f` gets the error, like for logging it or choosing the code by its kind.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let result = std::fs::read("does-not-exist.txt");
+    /// let code = result.code_or_else(|err| {
+    ///     eprintln!("could not read input: {err}");
+    ///     proc_exit::sysexits::NO_INPUT
+    /// });
+    /// assert_eq!(code, proc_exit::sysexits::NO_INPUT);
+    /// ```
+    #[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
+    fn code_or_else<F: FnOnce(E) -> crate::Code>(self, f: F) -> crate::Code;
+}
+
+impl<T, E> CodeResultExt<E> for Result<T, E> {
+    #[inline]
+    fn code_or(self, code: crate::Code) -> crate::Code {
+        match self {
+            Ok(_) => crate::Code::SUCCESS,
+            Err(_) => code,
+        }
+    }
+
+    #[inline]
+    fn code_or_else<F: FnOnce(E) -> crate::Code>(self, f: F) -> crate::Code {
+        match self {
+            Ok(_) => crate::Code::SUCCESS,
+            Err(err) => f(err),
+        }
+    }
+}
+
+/// The process succeeded
+///
+/// Shorthand for [`Code::SUCCESS.ok()`][crate::Code::ok].
+///
+/// Example:
+///
+/// ```
+/// fn run() -> proc_exit::ExitResult {
+///     proc_exit::success()
+/// }
+/// ```
+#[inline]
+pub fn success() -> ExitResult {
+    Ok(())
+}
+
+/// The process failed, reporting `msg`
+///
+/// Shorthand for [`Code::FAILURE.with_message(msg)`][crate::Code::with_message].
+///
+/// Example:
+///
+/// ```
+/// fn run() -> proc_exit::ExitResult {
+///     proc_exit::failure("nothing to do")
+/// }
+/// ```
+#[inline]
+pub fn failure<D: std::fmt::Display + 'static>(msg: D) -> ExitResult {
+    Err(crate::Code::FAILURE.with_message(msg))
+}
+
+/// Fail with `code` and `msg` unless `cond` holds, for checking preconditions
+///
+/// `msg` is taken like [`Code::with_message`][crate::Code::with_message].
+///
+/// Example:
+///
+/// ```
+/// fn run(args: &[&str]) -> proc_exit::ExitResult {
+///     proc_exit::require(args.len() == 2, proc_exit::bash::USAGE, "expected 2 arguments")?;
+///     proc_exit::success()
+/// }
+///
+/// assert!(run(&["a", "b"]).is_ok());
+/// assert_eq!(run(&["a"]).unwrap_err().code(), proc_exit::bash::USAGE);
+/// ```
+#[must_use = "the failure is lost unless propagated, like with `?`"]
+#[inline]
+pub fn require<D: std::fmt::Display + 'static>(
+    cond: bool,
+    code: crate::Code,
+    msg: D,
+) -> ExitResult {
+    if cond {
+        Ok(())
+    } else {
+        Err(code.with_message(msg))
+    }
+}
+
+/// Unwrap `opt` or fail with `code` and `msg`, for checking preconditions
+///
+/// `msg` is taken like [`Code::with_message`][crate::Code::with_message].  See
+/// [`WithCodeOptionExt::or_code`] for the method form.
+///
+/// Example:
+///
+/// ```
+/// fn run(args: &[&str]) -> proc_exit::ExitResult {
+///     let input = proc_exit::require_some(args.first(), proc_exit::bash::USAGE, "missing input")?;
+///     assert_eq!(*input, "a.txt");
+///     proc_exit::success()
+/// }
+///
+/// assert!(run(&["a.txt"]).is_ok());
+/// assert_eq!(run(&[]).unwrap_err().to_string(), "missing input");
+/// ```
+#[must_use = "the failure is lost unless propagated, like with `?`"]
+#[inline]
+pub fn require_some<T, D: std::fmt::Display + 'static>(
+    opt: Option<T>,
+    code: crate::Code,
+    msg: D,
+) -> Result<T, Exit> {
+    match opt {
+        Some(value) => Ok(value),
+        None => Err(code.with_message(msg)),
+    }
+}
+
+/// Run through every result, combining the failures
+///
+/// This drains `results` fully.  The combined [`Exit`] uses the code of the first failure and
+/// includes the messages of every failure, in order, see [`Exit::add_message`].
+///
+/// See [`collect_fail_fast`] to stop at the first failure.
+///
+/// Example:
+///
+/// ```
+/// let results = vec![
+///     proc_exit::Code::SUCCESS.ok(),
+///     Err(proc_exit::bash::USAGE.with_message("a.txt: invalid")),
+///     Err(proc_exit::Code::FAILURE.with_message("b.txt: invalid")),
+/// ];
+/// let exit = proc_exit::collect(results).unwrap_err();
+/// assert_eq!(exit.code(), proc_exit::bash::USAGE);
+/// assert_eq!(exit.to_string(), "a.txt: invalid\nb.txt: invalid");
+/// ```
+pub fn collect(results: impl IntoIterator<Item = ExitResult>) -> ExitResult {
+    let mut combined: Option<Exit> = None;
+    for result in results {
+        if let Err(mut err) = result {
+            match combined.as_mut() {
+                Some(combined) => combined.append_messages(&mut err),
+                None => combined = Some(err),
+            }
+        }
+    }
+    match combined {
+        Some(combined) => Err(combined),
+        None => Ok(()),
+    }
+}
+
+/// Run through the results until the first failure
+///
+/// This stops pulling from `results` on the first failure and returns it as-is, making it a
+/// fit for steps that depend on the success of earlier ones.
+///
+/// See [`collect`] to report every failure.
+///
+/// Example:
+///
+/// ```
+/// let steps = [proc_exit::bash::USAGE, proc_exit::Code::FAILURE];
+/// let mut ran = 0;
+/// let result = proc_exit::collect_fail_fast(steps.iter().map(|code| {
+///     ran += 1;
+///     code.ok()
+/// }));
+/// assert_eq!(result.unwrap_err().code(), proc_exit::bash::USAGE);
+/// assert_eq!(ran, 1);
+/// ```
+pub fn collect_fail_fast(results: impl IntoIterator<Item = ExitResult>) -> ExitResult {
+    results.into_iter().collect()
+}
+
+/// Run each command in turn, stopping at the first failure
+///
+/// This is the `status()?` / [`Code::from_status`][crate::Code::from_status] / `ok()?` loop
+/// that build scripts and `xtask` runners otherwise write by hand.  Commands inherit the
+/// current process' stdio unless configured otherwise, so each reports its own errors.
+///
+/// On failure, later commands are not run and the [`Exit`] is:
+/// - For a command that could not be started, [`Code::FAILURE`][crate::Code::FAILURE] with
+///   a message naming the program and the underlying error
+/// - For a command that exited unsuccessfully, its code with a message naming the program
+///
+/// Example:
+///
+/// ```
+/// let result = proc_exit::run_all(&mut [
+///     std::process::Command::new("true"),
+///     std::process::Command::new("false"),
+/// ]);
+/// assert_eq!(result.unwrap_err().to_string(), "`false` failed");
+/// ```
+pub fn run_all(commands: &mut [std::process::Command]) -> ExitResult {
+    for command in commands {
+        let program = command.get_program().to_string_lossy().into_owned();
+        let status = command.status().map_err(|err| {
+            crate::Code::FAILURE.with_message(format!("failed to run `{program}`: {err}"))
+        })?;
+        let code = crate::Code::from_status(status);
+        if code.is_err() {
+            return Err(code.with_message(format!("`{program}` failed")));
+        }
+    }
+    Ok(())
+}
+
+/// Run `f`, re-running it on transient failures
+///
+/// `f` is run up to `attempts` times (at least once), stopping early on success or on a failure
+/// whose code is not in `retry_on`.  The last result is returned.
+///
+/// See [`retry_with_backoff`] to wait between attempts.
+///
+/// Example:
+///
+/// ```
+/// let mut runs = 0;
+/// let result = proc_exit::retry(3, &[proc_exit::sysexits::TEMP_FAIL], || {
+///     runs += 1;
+///     proc_exit::sysexits::TEMP_FAIL.ok()
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(runs, 3);
+/// ```
+pub fn retry<F: FnMut() -> ExitResult>(
+    attempts: usize,
+    retry_on: &[crate::Code],
+    f: F,
+) -> ExitResult {
+    retry_with_backoff(attempts, retry_on, |_| {}, f)
+}
+
+/// Run `f`, re-running it on transient failures after calling `backoff`
+///
+/// This is [`retry`] except `backoff` is called before each re-run with the number of
+/// attempts made so far, e.g. to sleep.
+///
+/// Example:
+///
+/// ```
+/// let result = proc_exit::retry_with_backoff(
+///     3,
+///     &[proc_exit::sysexits::TEMP_FAIL],
+///     |attempt| std::thread::sleep(std::time::Duration::from_millis(10 * attempt as u64)),
+///     || proc_exit::Code::SUCCESS.ok(),
+/// );
+/// assert!(result.is_ok());
+/// ```
+pub fn retry_with_backoff<B: FnMut(usize), F: FnMut() -> ExitResult>(
+    attempts: usize,
+    retry_on: &[crate::Code],
+    mut backoff: B,
+    mut f: F,
+) -> ExitResult {
+    let mut attempt = 1;
+    loop {
+        let result = f();
+        match &result {
+            Err(err) if attempt < attempts && err.code.is_one_of(retry_on) => {
+                backoff(attempt);
+                attempt += 1;
+            }
+            _ => return result,
+        }
+    }
+}
+
+/// The code of a pipeline, from the statuses of its commands in order, like bash
+///
+/// - Without `pipefail`, the code of the last command
+/// - With `pipefail`, the code of the last command to fail, or [`Code::SUCCESS`][crate::Code::SUCCESS]
+///   if all succeeded.  Like bash, this is the rightmost failure, not the first.
+///
+/// An empty pipeline is [`Code::SUCCESS`][crate::Code::SUCCESS].  Each status is converted with
+/// [`Code::from_status`][crate::Code::from_status].
+///
+/// Example:
+///
+/// ```
+/// # #[cfg(unix)] {
+/// use std::os::unix::process::ExitStatusExt as _;
+/// use std::process::ExitStatus;
+///
+/// // `false | true`
+/// let statuses = [ExitStatus::from_raw(1 << 8), ExitStatus::from_raw(0)];
+/// assert_eq!(proc_exit::pipeline_code(&statuses, false), proc_exit::Code::SUCCESS);
+/// assert_eq!(proc_exit::pipeline_code(&statuses, true), proc_exit::Code::FAILURE);
+/// # }
+/// ```
+pub fn pipeline_code(statuses: &[std::process::ExitStatus], pipefail: bool) -> crate::Code {
+    let mut codes = statuses.iter().rev().map(|s| crate::Code::from_status(*s));
+    let code = if pipefail {
+        codes.find(|c| c.is_err())
+    } else {
+        codes.next()
+    };
+    code.unwrap_or(crate::Code::SUCCESS)
+}
+
 /// Report any error message and exit.
+///
+/// There is no equivalent for an arbitrary [`Termination`][std::process::Termination]: its
+/// [`ExitCode`][std::process::ExitCode] can't be read back, and even telling success from
+/// failure needs `ExitCode: PartialEq` from Rust 1.79, above our MSRV.  Convert to an
+/// [`ExitResult`] or a [`Code`][crate::Code] instead, or return the `Termination` from `main`.
 #[inline]
 pub fn exit(result: ExitResult) -> ! {
     let code = report(result);
     code.process_exit()
 }
 
+/// Report each failure as it happens, then exit with the [worst][crate::Code::worst] code
+///
+/// This drains `results` fully, not stopping at the first failure.  Failures are reported in the
+/// order they are produced, like [`report`].  Exits with [`Code::SUCCESS`][crate::Code::SUCCESS]
+/// if `results` is empty or all succeeded.
+///
+/// See [`collect`] to combine the failures instead of reporting them.
+///
+/// Example:
+///
+/// ```no_run
+/// let actions = ["build", "test"];
+/// proc_exit::drain_then_exit(actions.iter().map(|action| {
+///     proc_exit::Code::FAILURE.to_result_with_else((), || format!("{action} failed"))
+/// }));
+/// ```
+pub fn drain_then_exit<I: Iterator<Item = ExitResult>>(results: I) -> ! {
+    let mut code = crate::Code::SUCCESS;
+    for result in results {
+        code = code.worst(report(result));
+    }
+    code.process_exit()
+}
+
+/// [`exit`][std::process::exit] with `code` now, without reporting anything
+///
+/// This is [`Code::process_exit`][crate::Code::process_exit] as a free function.
+///
+/// Example:
+///
+/// ```no_run
+/// proc_exit::exit_with_code(proc_exit::bash::USAGE);
+/// ```
+#[inline]
+pub fn exit_with_code(code: crate::Code) -> ! {
+    code.process_exit()
+}
+
+/// Report every failure, delegating exiting to the caller.
+///
+/// Messages are written to `w` in order, with any hints, like [`Exit::write_to`].  Exits
+/// without a message still contribute their code.  The returned code is the
+/// [worst][crate::Code::worst] of them, or [`Code::SUCCESS`][crate::Code::SUCCESS] if there
+/// are none.
+///
+/// Example:
+///
+/// ```
+/// let exits = vec![
+///     proc_exit::Code::FAILURE.as_exit(),
+///     proc_exit::bash::USAGE.with_message("a.txt: invalid"),
+///     proc_exit::bash::SIGINT.as_exit(),
+/// ];
+/// let mut buffer = Vec::new();
+/// let code = proc_exit::report_all(exits, &mut buffer);
+/// assert_eq!(code, proc_exit::bash::SIGINT);
+/// assert_eq!(buffer, b"a.txt: invalid\n");
+/// ```
+#[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
+pub fn report_all(exits: Vec<Exit>, w: &mut impl Write) -> crate::Code {
+    let mut code = crate::Code::SUCCESS;
+    for exit in exits {
+        // Keep going, so the code still accounts for every failure
+        let _ = exit.write_to(w);
+        code = code.worst(exit.code);
+    }
+    code
+}
+
 /// Report, delegating exiting to the caller.
+///
+/// See [`Reporter`][crate::Reporter] to customize what is reported.
+///
+/// Forgetting to exit with the code is caught by the `unused_must_use` lint:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+///
+/// proc_exit::report(proc_exit::Code::FAILURE.ok());
+/// ```
+#[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
 #[inline]
 pub fn report(result: ExitResult) -> crate::Code {
-    match result {
-        Ok(()) => crate::Code::SUCCESS,
-        Err(err) => {
-            if let Some(msg) = err.msg {
-                // At this point, we might be exiting due to a broken pipe, just do our best and
-                // move on.
-                let _ = writeln!(std::io::stderr(), "{msg}");
-            }
-            err.code
-        }
-    }
+    crate::Reporter::new().report(result)
+}
+
+/// Report, writing `note` as a warning on success, and delegating exiting to the caller.
+///
+/// The code stays [`Code::SUCCESS`][crate::Code::SUCCESS] on success.  See
+/// [`Reporter::report_success_with`][crate::Reporter::report_success_with].
+#[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
+#[inline]
+pub fn report_success_with<D: std::fmt::Display>(result: ExitResult, note: D) -> crate::Code {
+    crate::Reporter::new().report_success_with(result, note)
 }
@@ -0,0 +1,42 @@
+//! Exit with a [`eyre`](https://docs.rs/eyre) report
+//!
+//! Example:
+//!
+//! ```
+//! use proc_exit::prelude::*;
+//!
+//! fn run() -> proc_exit::ExitResult {
+//!     let result: Result<(), eyre::Report> = Err(eyre::eyre!("config not found"));
+//!     result.with_code_report(proc_exit::sysexits::CONFIG_ERR)?;
+//!     proc_exit::Code::SUCCESS.ok()
+//! }
+//!
+//! assert!(run().is_err());
+//! ```
+
+/// Extension for converting a [`eyre::Report`] to [`Exit`][crate::Exit]
+///
+/// Unlike [`WithCodeResultExt::with_code`][crate::WithCodeResultExt::with_code], which only
+/// shows the report's top-level message, this renders the report the way its installed handler
+/// would, including causes and any other details.
+pub trait WithCodeReportExt<T> {
+    /// Convert a [`eyre::Report`] into an [`Exit`][crate::Exit]
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
+    fn with_code_report(self, code: crate::Code) -> Result<T, crate::Exit>;
+}
+
+impl<T> WithCodeReportExt<T> for Result<T, eyre::Report> {
+    #[inline]
+    fn with_code_report(self, code: crate::Code) -> Result<T, crate::Exit> {
+        self.map_err(|e| crate::Exit::new(code).with_message(Rendered(e)))
+    }
+}
+
+struct Rendered(eyre::Report);
+
+impl std::fmt::Display for Rendered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Reports render their full diagnostic through `Debug`
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,16 @@
+//! [git](https://git-scm.com) exit codes
+//!
+//! For tools that wrap or emulate `git`.
+//!
+//! **Note:** these overlap with [`bash`][crate::bash] codes, so a code can't tell which
+//! convention a process followed:
+//! - [`FATAL`] is [`bash::INVALID_EXIT`][crate::bash::INVALID_EXIT]
+//! - [`USAGE`] is [`bash::SIGHUP`][crate::bash::SIGHUP]
+//!
+//! [`Code::name`][crate::Code::name] and friends report the `bash` names for these.
+
+/// Fatal error, from git's `die()`
+pub const FATAL: crate::Code = crate::Code::new(128);
+
+/// Command line usage error, from git's `usage()`
+pub const USAGE: crate::Code = crate::Code::new(129);
@@ -0,0 +1,56 @@
+//! Exiting under fuzzers, sanitizers, and test harnesses
+//!
+//! [`proc_exit::exit`][crate::exit] calls [`std::process::exit`] which ends the process without
+//! running destructors or giving tooling a chance to inspect it.  Depending on what is driving
+//! the process, a different ending may be more useful:
+//! - [`exit`] reports the message and records the [`Code`][crate::Code] in a thread-local,
+//!   returning control to the caller.  Use this from fuzz targets and tests that want to assert
+//!   on the intended code while continuing to run (e.g. so leak detection still happens).
+//! - [`abort`] reports the message and calls [`std::process::abort`].  Use this when a non-zero
+//!   exit is a bug the fuzzer or sanitizer should flag as a crash.
+//!
+//! Example:
+//!
+//! ```
+//! fn run() -> proc_exit::ExitResult {
+//!     proc_exit::bash::USAGE.ok()
+//! }
+//!
+//! let code = proc_exit::harness::exit(run());
+//! assert_eq!(code, proc_exit::bash::USAGE);
+//! assert_eq!(proc_exit::harness::take_code(), Some(proc_exit::bash::USAGE));
+//! assert_eq!(proc_exit::harness::take_code(), None);
+//! ```
+
+use std::cell::Cell;
+
+thread_local! {
+    static LAST_CODE: Cell<Option<crate::Code>> = const { Cell::new(None) };
+}
+
+/// Report any error message and record the code, returning to the caller.
+///
+/// The code is available from [`take_code`] until the next call to `exit` on this thread.
+#[inline]
+pub fn exit(result: crate::ExitResult) -> crate::Code {
+    let code = crate::report(result);
+    LAST_CODE.with(|last| last.set(Some(code)));
+    code
+}
+
+/// The code recorded by the last call to [`exit`] on this thread, clearing it.
+#[inline]
+pub fn take_code() -> Option<crate::Code> {
+    LAST_CODE.with(|last| last.take())
+}
+
+/// Report any error message and, on failure, [`abort`][std::process::abort].
+///
+/// Success returns normally so the harness can move on to the next input.
+#[inline]
+pub fn abort(result: crate::ExitResult) {
+    let code = crate::report(result);
+    if code.is_err() {
+        std::process::abort()
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::Write;
+
+pub(crate) fn write_exit(
+    err: &crate::Exit,
+    max_message_bytes: Option<usize>,
+    w: &mut impl Write,
+) -> std::io::Result<()> {
+    let code = err.code();
+    write!(
+        w,
+        "{{\"schema\":{},\"code\":{}",
+        crate::Reporter::JSON_SCHEMA,
+        code.as_raw()
+    )?;
+    write!(w, ",\"name\":")?;
+    match code.name() {
+        Some(name) => write_str(name, w)?,
+        None => write!(w, "null")?,
+    }
+    write!(w, ",\"category\":")?;
+    write_str(category_id(code.category()), w)?;
+    write!(w, ",\"messages\":[")?;
+    for (i, msg) in err.messages().enumerate() {
+        if i != 0 {
+            write!(w, ",")?;
+        }
+        let mut msg = msg.to_string();
+        if let Some(max) = max_message_bytes {
+            crate::reporter::truncate(&mut msg, max);
+        }
+        write_str(&msg, w)?;
+    }
+    write!(w, "],\"hint\":")?;
+    match err.hint() {
+        Some(hint) => write_str(&hint.to_string(), w)?,
+        None => write!(w, "null")?,
+    }
+    write!(w, ",\"app_code\":")?;
+    match err.app_code() {
+        Some(app_code) => write_str(&app_code.to_string(), w)?,
+        None => write!(w, "null")?,
+    }
+    writeln!(w, "}}")
+}
+
+fn category_id(category: crate::CodeCategory) -> &'static str {
+    match category {
+        crate::CodeCategory::Success => "success",
+        crate::CodeCategory::GenericFailure => "generic_failure",
+        crate::CodeCategory::Usage => "usage",
+        crate::CodeCategory::Sysexit => "sysexit",
+        crate::CodeCategory::Signal => "signal",
+        crate::CodeCategory::ShellReserved => "shell_reserved",
+        crate::CodeCategory::Other => "other",
+    }
+}
+
+fn write_str(s: &str, w: &mut impl Write) -> std::io::Result<()> {
+    write!(w, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(w, "\\\"")?,
+            '\\' => write!(w, "\\\\")?,
+            '\n' => write!(w, "\\n")?,
+            '\r' => write!(w, "\\r")?,
+            '\t' => write!(w, "\\t")?,
+            c if c.is_control() => write!(w, "\\u{:04x}", c as u32)?,
+            c => write!(w, "{c}")?,
+        }
+    }
+    write!(w, "\"")
+}
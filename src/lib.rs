@@ -7,6 +7,20 @@
 //! - Integrate with `main`, `std::process`, and `std::io::Error`
 //! - Supports exiting silently (error message reported through another means)
 //!
+//! Optional features:
+//! - `assert_cmd`: assert on a [`Code`] in tests, see [`assert_cmd`][crate::assert_cmd]
+//! - `backtrace`: capture a backtrace when creating an [`Exit`], see [`Exit::backtrace`]
+//! - `clap`: parse [`Code`] as a command-line argument via [`clap::code_parser`]
+//! - `derive`: map error enum variants to codes via [`#[derive(ExitCode)]`][derive@ExitCode]
+//! - `duct`: run `duct` expressions via [`duct::DuctExt`]
+//! - `eyre`: exit with an `eyre::Report` via [`eyre::WithCodeReportExt`]
+//! - `miette`: exit with a `miette::Report` via [`miette::WithCodeReportExt`]
+//! - `nix`: convert `nix::sys::wait::WaitStatus` on Unix via [`Code::from_wait_status`]
+//! - `sysexits-crate`: convert between [`Code`] and [`sysexits::ExitCode`][::sysexits::ExitCode]
+//! - `syslog`: report to the system log on Unix via [`Reporter::syslog`]
+//! - `tracing`: emit a `tracing` event when [reporting][Reporter::report]
+//! - `wrap`: wrap messages to the terminal width via [`Reporter::wrap`]
+//!
 //! ## Install
 //!
 //! Add to your `Cargo.toml`:
@@ -84,19 +98,111 @@
 #![warn(clippy::print_stderr)]
 #![warn(clippy::print_stdout)]
 
+#[cfg(target_has_atomic = "32")]
+mod atomic;
 mod code;
+mod command;
 mod exit;
+mod json;
+mod locale;
+mod name;
+#[cfg(all(unix, feature = "nix"))]
+mod nix;
+mod policy;
+mod portable;
+mod reporter;
+mod shell;
+mod summary;
+#[cfg(feature = "sysexits-crate")]
+mod sysexits_crate;
+mod tally;
 
-/// Easy access to traits
+/// Easy access to traits and the core types
+///
+/// This brings in:
+/// - The extension traits, anonymously, for their methods
+/// - [`Code`], [`Exit`], and [`ExitResult`]
+///
+/// Constants are left out to avoid polluting the namespace; they are a `Code::` away, e.g.
+/// `Code::SUCCESS`.
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::prelude::*;
+///
+/// fn run() -> ExitResult {
+///     Code::SUCCESS.ok()
+/// }
+/// ```
 pub mod prelude {
+    pub use super::Code;
+    pub use super::CodeResultExt as _;
+    pub use super::CommandExt as _;
+    pub use super::Exit;
+    pub use super::ExitResult;
+    pub use super::ExitResultExt as _;
+    pub use super::WithCodeOptionExt as _;
     pub use super::WithCodeResultExt as _;
+    #[cfg(feature = "duct")]
+    pub use crate::duct::DuctExt as _;
+    #[cfg(feature = "eyre")]
+    pub use crate::eyre::WithCodeReportExt as _;
+    #[cfg(feature = "miette")]
+    pub use crate::miette::WithCodeReportExt as _;
     pub use crate::sysexits::ToSysexitsResultExt as _;
 }
 
+#[cfg(feature = "assert_cmd")]
+pub mod assert_cmd;
 pub mod bash;
+#[cfg(feature = "clap")]
+pub mod clap;
+pub mod container;
+#[cfg(feature = "duct")]
+pub mod duct;
+#[cfg(feature = "eyre")]
+pub mod eyre;
+pub mod git;
+pub mod harness;
+#[cfg(feature = "miette")]
+pub mod miette;
+pub mod rust;
+pub mod supervisor;
 pub mod sysexits;
 
+#[cfg(target_has_atomic = "32")]
+pub use atomic::AtomicCode;
 pub use code::Code;
+pub use code::CodeCategory;
+pub use code::CodeRangeError;
+pub use code::Convention;
+pub use code::ParseCodeError;
+pub use code::UnixExitKind;
+pub use command::reexec;
+pub use command::CommandExt;
+pub use exit::pipeline_code;
+pub use exit::run_all;
+pub use exit::CodeResultExt;
+pub use exit::ExitResultExt;
+pub use exit::WithCodeOptionExt;
 pub use exit::WithCodeResultExt;
-pub use exit::{exit, report};
+pub use exit::{collect, collect_fail_fast};
+pub use exit::{drain_then_exit, exit, exit_with_code, report, report_all, report_success_with};
+pub use exit::{failure, require, require_some, success};
+pub use exit::{retry, retry_with_backoff};
 pub use exit::{Exit, ExitResult};
+pub use policy::CodePolicy;
+pub use policy::PolicyError;
+pub use portable::PortableCode;
+pub use reporter::CiFormat;
+pub use reporter::Reporter;
+#[cfg(all(unix, feature = "syslog"))]
+pub use reporter::Syslog;
+pub use reporter::Verbosity;
+pub use shell::{detect_shell, Shell};
+pub use summary::ExitSummary;
+pub use tally::{RunTally, TallyPolicy};
+
+#[cfg(feature = "derive")]
+pub use proc_exit_derive::ExitCode;
@@ -0,0 +1,53 @@
+use std::cell::RefCell;
+
+thread_local! {
+    static CURRENT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Call `f` with the locale messages are rendered in
+///
+/// This is the one set by [`Reporter::locale`][crate::Reporter::locale] while reporting,
+/// falling back to the POSIX environment (`LC_ALL`, `LC_MESSAGES`, `LANG`) and then `C`.
+pub(crate) fn with_current<R>(f: impl FnOnce(&str) -> R) -> R {
+    let scoped = CURRENT.with(|current| current.borrow().clone());
+    match scoped {
+        Some(locale) => f(&locale),
+        None => f(&from_env()),
+    }
+}
+
+fn from_env() -> String {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| "C".to_owned())
+}
+
+/// Render messages in `locale` until dropped
+pub(crate) struct Scope {
+    previous: Option<String>,
+}
+
+impl Scope {
+    pub(crate) fn new(locale: &str) -> Self {
+        let previous = CURRENT.with(|current| current.replace(Some(locale.to_owned())));
+        Self { previous }
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| current.replace(previous));
+    }
+}
+
+/// A message rendered for the [current locale][with_current] each time it is displayed
+pub(crate) struct LocalizedMessage<F>(pub(crate) F);
+
+impl<F: Fn(&str) -> String> std::fmt::Display for LocalizedMessage<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        with_current(|locale| f.write_str(&(self.0)(locale)))
+    }
+}
@@ -0,0 +1,57 @@
+//! Symbolic names for well-known exit codes
+
+/// A well-known exit code
+pub(crate) struct Entry {
+    pub(crate) name: &'static str,
+    pub(crate) code: crate::Code,
+    pub(crate) description: &'static str,
+}
+
+impl Entry {
+    pub(crate) const fn new(
+        name: &'static str,
+        code: crate::Code,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            code,
+            description,
+        }
+    }
+}
+
+/// Codes defined directly on [`Code`][crate::Code]
+pub(crate) const GENERIC: &[Entry] = &[
+    Entry::new("SUCCESS", crate::Code::SUCCESS, "success"),
+    Entry::new("FAILURE", crate::Code::FAILURE, "failure"),
+];
+
+/// All known names, in order of precedence
+pub(crate) fn entries() -> impl Iterator<Item = &'static Entry> {
+    GENERIC
+        .iter()
+        .chain(crate::bash::NAMES)
+        .chain(crate::sysexits::NAMES)
+}
+
+pub(crate) fn lookup_code(code: crate::Code) -> Option<&'static Entry> {
+    entries().find(|e| e.code == code)
+}
+
+pub(crate) fn lookup_code_with(
+    code: crate::Code,
+    convention: crate::Convention,
+) -> Option<&'static Entry> {
+    match convention {
+        crate::Convention::Bash => lookup_code(code),
+        crate::Convention::Sysexits => crate::sysexits::NAMES
+            .iter()
+            .find(|e| e.code == code)
+            .or_else(|| lookup_code(code)),
+    }
+}
+
+pub(crate) fn lookup_name(name: &str) -> Option<&'static Entry> {
+    entries().find(|e| e.name == name)
+}
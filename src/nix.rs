@@ -0,0 +1,56 @@
+use ::nix::sys::signal::Signal;
+use ::nix::sys::wait::WaitStatus;
+
+impl crate::Code {
+    /// Converts a [`WaitStatus`] to [`Code`][crate::Code]
+    ///
+    /// Each variant is mapped like [`Code::from_status`][crate::Code::from_status] would for the
+    /// same raw status:
+    /// - `Exited`: the exit code
+    /// - `Signaled`: the number of the signal that terminated the process.  Whether a core was
+    ///   dumped isn't represented, see [`Code::decode_unix`][crate::Code::decode_unix].
+    /// - `Stopped`: the number of the signal that stopped the process, as the status is only
+    ///   reported with `WUNTRACED` which asks for job control
+    /// - `Continued`: the [default][crate::Code::default], as the process hasn't ended
+    /// - `PtraceEvent`: the number of the signal that stopped the process
+    /// - `PtraceSyscall`: `SIGTRAP`, the signal a syscall stop is reported with
+    /// - `StillAlive`: the [default][crate::Code::default], as there is no status yet, like
+    ///   from `WNOHANG`
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use nix::sys::signal::Signal;
+    /// use nix::sys::wait::WaitStatus;
+    /// use nix::unistd::Pid;
+    /// use proc_exit::Code;
+    ///
+    /// let pid = Pid::from_raw(1);
+    /// assert_eq!(Code::from_wait_status(WaitStatus::Exited(pid, 3)), Code::new(3));
+    /// assert_eq!(
+    ///     Code::from_wait_status(WaitStatus::Signaled(pid, Signal::SIGKILL, false)),
+    ///     Code::new(9)
+    /// );
+    /// assert_eq!(Code::from_wait_status(WaitStatus::StillAlive), Code::default());
+    /// ```
+    pub fn from_wait_status(status: WaitStatus) -> Self {
+        match status {
+            WaitStatus::Exited(_, code) => Self::new(code),
+            WaitStatus::Signaled(_, signal, _core_dumped) => Self::new(signal as i32),
+            WaitStatus::Stopped(_, signal) => Self::new(signal as i32),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            WaitStatus::PtraceEvent(_, signal, _) => Self::new(signal as i32),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            WaitStatus::PtraceSyscall(_) => Self::new(Signal::SIGTRAP as i32),
+            WaitStatus::Continued(_) | WaitStatus::StillAlive => Self::default(),
+        }
+    }
+}
+
+/// See [`Code::from_wait_status`][crate::Code::from_wait_status]
+impl From<WaitStatus> for crate::Code {
+    #[inline]
+    fn from(status: WaitStatus) -> Self {
+        Self::from_wait_status(status)
+    }
+}
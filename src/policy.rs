@@ -0,0 +1,128 @@
+/// Rules for which [`Code`][crate::Code]s an application may emit
+///
+/// Start from a preset and adjust it with the builder methods:
+/// - [`CodePolicy::ANY`]: every code is allowed
+/// - [`CodePolicy::PORTABLE`]: only codes every platform can deliver, see
+///   [`Code::is_portable`][crate::Code::is_portable]
+/// - [`CodePolicy::APPLICATION`]: portable codes that can't be confused with the shell's, i.e.
+///   no signal or reserved codes
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::{Code, CodePolicy};
+///
+/// assert!(Code::new_with_policy(3, CodePolicy::APPLICATION).is_ok());
+/// assert!(Code::new_with_policy(127, CodePolicy::APPLICATION).is_err());
+/// assert!(Code::new_with_policy(127, CodePolicy::APPLICATION.allow_reserved(true)).is_ok());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CodePolicy {
+    portable_only: bool,
+    allow_signals: bool,
+    allow_reserved: bool,
+}
+
+impl CodePolicy {
+    /// Every code is allowed
+    pub const ANY: Self = Self {
+        portable_only: false,
+        allow_signals: true,
+        allow_reserved: true,
+    };
+
+    /// Only codes that are delivered unchanged on every platform
+    pub const PORTABLE: Self = Self {
+        portable_only: true,
+        allow_signals: true,
+        allow_reserved: true,
+    };
+
+    /// Portable codes that a shell won't interpret as its own
+    ///
+    /// This is a good fit for libraries embedded in other programs or scripts.
+    pub const APPLICATION: Self = Self {
+        portable_only: true,
+        allow_signals: false,
+        allow_reserved: false,
+    };
+
+    /// Restrict codes to `0..=255`
+    pub const fn portable_only(mut self, yes: bool) -> Self {
+        self.portable_only = yes;
+        self
+    }
+
+    /// Allow codes a shell reports for a process killed by a signal, see
+    /// [`bash::SIGHUP`][crate::bash::SIGHUP] and friends
+    pub const fn allow_signals(mut self, yes: bool) -> Self {
+        self.allow_signals = yes;
+        self
+    }
+
+    /// Allow codes a shell reserves for its own errors, like
+    /// [`bash::NOT_FOUND`][crate::bash::NOT_FOUND]
+    pub const fn allow_reserved(mut self, yes: bool) -> Self {
+        self.allow_reserved = yes;
+        self
+    }
+
+    /// Check `code` against the policy
+    pub const fn check(self, code: crate::Code) -> Result<crate::Code, PolicyError> {
+        let violation = if self.portable_only && !code.is_portable() {
+            Some(Violation::NotPortable)
+        } else if !self.allow_reserved && crate::bash::is_reserved(code) {
+            Some(Violation::Reserved)
+        } else if !self.allow_signals && crate::bash::is_signal(code) {
+            Some(Violation::Signal)
+        } else {
+            None
+        };
+        match violation {
+            Some(violation) => Err(PolicyError { code, violation }),
+            None => Ok(code),
+        }
+    }
+}
+
+impl Default for CodePolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::ANY
+    }
+}
+
+/// A [`Code`][crate::Code] was rejected by a [`CodePolicy`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PolicyError {
+    code: crate::Code,
+    violation: Violation,
+}
+
+impl PolicyError {
+    /// The rejected code
+    #[inline]
+    pub const fn code(&self) -> crate::Code {
+        self.code
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Violation {
+    NotPortable,
+    Signal,
+    Reserved,
+}
+
+impl std::fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = self.code.as_raw();
+        match self.violation {
+            Violation::NotPortable => write!(f, "exit code {code} is not portable"),
+            Violation::Signal => write!(f, "exit code {code} is reserved for signals"),
+            Violation::Reserved => write!(f, "exit code {code} is reserved by the shell"),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
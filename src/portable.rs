@@ -0,0 +1,97 @@
+/// A [`Code`][crate::Code] that is known to be [portable][crate::Code::is_portable]
+///
+/// Validate a code once, with [`Code::try_into_portable`][crate::Code::try_into_portable], and
+/// then carry the guarantee that it converts to [`ExitCode`][std::process::ExitCode] and is
+/// delivered unchanged on every platform.
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::{Code, PortableCode};
+///
+/// let code: PortableCode = Code::new(3).try_into_portable().unwrap();
+/// assert_eq!(code.get(), 3);
+/// let exit_code = std::process::ExitCode::from(code);
+/// assert_eq!(Code::from(code), Code::new(3));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PortableCode(u8);
+
+impl PortableCode {
+    /// The process exited successfully.
+    pub const SUCCESS: Self = Self(0);
+
+    /// Generic failure.
+    pub const FAILURE: Self = Self(1);
+
+    /// Create a portable code
+    #[inline]
+    pub const fn new(code: u8) -> Self {
+        Self(code)
+    }
+
+    /// The raw value
+    #[inline]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Convert to [`Code`][crate::Code]
+    #[inline]
+    pub const fn as_code(self) -> crate::Code {
+        crate::Code::new(self.0 as i32)
+    }
+
+    /// Convert to [`ExitCode`][std::process::ExitCode]
+    ///
+    /// Unlike [`Code::as_exit_code`][crate::Code::as_exit_code], this can't fail.
+    #[inline]
+    pub fn as_exit_code(self) -> std::process::ExitCode {
+        self.0.into()
+    }
+}
+
+impl From<u8> for PortableCode {
+    #[inline]
+    fn from(code: u8) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<PortableCode> for crate::Code {
+    #[inline]
+    fn from(code: PortableCode) -> Self {
+        code.as_code()
+    }
+}
+
+impl From<PortableCode> for std::process::ExitCode {
+    #[inline]
+    fn from(code: PortableCode) -> Self {
+        code.as_exit_code()
+    }
+}
+
+/// See [`Code::try_into_portable`][crate::Code::try_into_portable]
+impl TryFrom<crate::Code> for PortableCode {
+    type Error = crate::CodeRangeError;
+
+    #[inline]
+    fn try_from(code: crate::Code) -> Result<Self, Self::Error> {
+        code.try_into_portable()
+    }
+}
+
+impl std::fmt::Display for PortableCode {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::process::Termination for PortableCode {
+    #[inline]
+    fn report(self) -> std::process::ExitCode {
+        self.as_exit_code()
+    }
+}
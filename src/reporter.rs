@@ -0,0 +1,991 @@
+use std::io::Write;
+
+/// Configurable [`report`][crate::report]
+///
+/// Example:
+///
+/// ```
+/// use proc_exit::{Reporter, Verbosity};
+///
+/// fn run() -> proc_exit::ExitResult {
+///     proc_exit::Code::SUCCESS.ok()
+/// }
+///
+/// let quiet = true;
+/// let verbosity = if quiet { Verbosity::Quiet } else { Verbosity::Normal };
+/// let code = Reporter::new().verbosity(verbosity).report(run());
+/// assert_eq!(code, proc_exit::Code::SUCCESS);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Reporter {
+    verbosity: Verbosity,
+    max_message_bytes: Option<usize>,
+    env_override: Option<String>,
+    describe_known: bool,
+    program_name: Option<String>,
+    line_prefix: Option<String>,
+    locale: Option<String>,
+    json: bool,
+    summary_line: bool,
+    warn_nonportable: bool,
+    sanitize: bool,
+    debug_assertions: bool,
+    ci_annotations: CiFormat,
+    tee: Option<std::path::PathBuf>,
+    tee_strict: bool,
+    dedupe: Option<std::sync::Arc<std::sync::Mutex<Dedupe>>>,
+    #[cfg(feature = "wrap")]
+    wrap: bool,
+    #[cfg(unix)]
+    status_fd: Option<std::os::fd::BorrowedFd<'static>>,
+    #[cfg(all(unix, feature = "syslog"))]
+    syslog: Syslog,
+}
+
+impl Reporter {
+    /// Version of the JSON written with [`Reporter::json`]
+    ///
+    /// This is bumped whenever a field is removed or changes meaning.  Adding a field does not
+    /// bump it, so parsers should ignore unknown fields.
+    pub const JSON_SCHEMA: u32 = 1;
+
+    /// Report like [`report`][crate::report]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How much to report on failure
+    pub fn verbosity(mut self, level: Verbosity) -> Self {
+        self.verbosity = level;
+        self
+    }
+
+    /// Truncate messages longer than `max` bytes, ending them with `…`
+    ///
+    /// This guards against flooding the terminal or logs, like when forwarding a misbehaving
+    /// child's output.  The `…` counts towards `max` and is left off when `max` is too small to
+    /// fit it.  Truncation happens on a `char` boundary, so it may keep slightly fewer than `max`
+    /// bytes.  Any hint is written in full.
+    pub fn max_message_bytes(mut self, max: usize) -> Self {
+        self.max_message_bytes = Some(max);
+        self
+    }
+
+    /// Prefix each message with the program's name, like `mytool: permission denied`
+    ///
+    /// See [`Reporter::program_name_from_args`] to use the name the program was run as.
+    pub fn program_name(mut self, name: impl Into<String>) -> Self {
+        self.program_name = Some(name.into());
+        self
+    }
+
+    /// Prefix each message with the file name of the program, from [`std::env::args_os`]
+    ///
+    /// When the program name is unavailable, messages are not prefixed.
+    pub fn program_name_from_args(mut self) -> Self {
+        self.program_name = std::env::args_os().next().and_then(|arg0| {
+            std::path::Path::new(&arg0)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        });
+        self
+    }
+
+    /// Don't prefix messages with the program's name
+    ///
+    /// This is the default.
+    pub fn no_program_name(mut self) -> Self {
+        self.program_name = None;
+        self
+    }
+
+    /// Prefix every line of every message, like `error: `
+    ///
+    /// This gives multi-line messages, like a formatted diagnostic, a consistent gutter.  A
+    /// trailing newline does not get a prefixed empty line.  The
+    /// [program name][Reporter::program_name] comes first, on the first line of each message, e.g.
+    /// `mytool: error: first line` followed by `error: second line`.  The prefix is written as-is,
+    /// so it may include ANSI escape codes for color.  Lines added by [wrapping][Reporter::wrap]
+    /// are not prefixed.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().line_prefix("error: ");
+    /// ```
+    pub fn line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.line_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Render [localized messages][crate::Exit::with_message_fn] in `locale`
+    ///
+    /// By default, the locale comes from the `LC_ALL`, `LC_MESSAGES`, and `LANG` environment
+    /// variables.  This only affects messages set with
+    /// [`Exit::with_message_fn`][crate::Exit::with_message_fn].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().locale("de_DE");
+    /// ```
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Write each failure as a single line of JSON, for other programs to parse
+    ///
+    /// The object has the fields:
+    /// - `schema`: [`Reporter::JSON_SCHEMA`]
+    /// - `code`: the [raw][crate::Code::as_raw] code, before any
+    ///   [override][Reporter::env_override]
+    /// - `name`: the [name][crate::Code::name] of the code or `null`
+    /// - `category`: the [category][crate::Code::category] as one of `success`,
+    ///   `generic_failure`, `usage`, `sysexit`, `signal`, `shell_reserved`, or `other`
+    /// - `messages`: an array of the messages, possibly [truncated][Reporter::max_message_bytes]
+    /// - `hint`: the [hint][crate::Exit::hint] or `null`
+    /// - `app_code`: the [application error code][crate::Exit::app_code] or `null`
+    ///
+    /// Like text, nothing is written on success or with [`Verbosity::Quiet`].  Other formatting
+    /// options, like the [program name][Reporter::program_name], don't apply.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().json(true);
+    /// ```
+    ///
+    /// For `Err(proc_exit::bash::USAGE.with_message("missing `--input`"))`, this writes:
+    ///
+    /// ```json
+    /// {"schema":1,"code":2,"name":"USAGE","category":"usage","messages":["missing `--input`"],"hint":null,"app_code":null}
+    /// ```
+    pub fn json(mut self, yes: bool) -> Self {
+        self.json = yes;
+        self
+    }
+
+    /// End with a line summarizing the outcome, for scripts to find
+    ///
+    /// The line is `exit: <code> (<category>)`, with the [raw][crate::Code::as_raw] code that
+    /// will be exited with and its [category][crate::Code::category], e.g. `exit: 2 (usage error)`
+    /// or `exit: 0 (success)`.  It is written on success as well, but not with
+    /// [`Verbosity::Quiet`] or [`Reporter::json`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().summary_line(true);
+    /// ```
+    pub fn summary_line(mut self, yes: bool) -> Self {
+        self.summary_line = yes;
+        self
+    }
+
+    /// Warn when exiting with a code that isn't [portable][crate::Code::is_portable]
+    ///
+    /// On Unix, such a code is silently truncated to its low 8 bits, see
+    /// [`Code::normalize`][crate::Code::normalize].  This is meant for catching those bugs in
+    /// development and CI.  The warning is written to `stderr` at most once per process,
+    /// regardless of [`Verbosity`], as it is a bug in the program rather than a failure to report.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().warn_nonportable(cfg!(debug_assertions));
+    /// ```
+    pub fn warn_nonportable(mut self, yes: bool) -> Self {
+        self.warn_nonportable = yes;
+        self
+    }
+
+    /// Also write failures as annotations for a CI platform
+    ///
+    /// The annotation is written to `stderr`, after the failure's regular output, with all of
+    /// its messages on one line.  See [`CiFormat`] for the exact output of each platform.  Like
+    /// the regular output, nothing is written on success or with [`Verbosity::Quiet`].
+    ///
+    /// This is off by default, see [`CiFormat::detect`] to choose based on the environment.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{CiFormat, Reporter};
+    ///
+    /// let reporter = Reporter::new().ci_annotations(CiFormat::detect());
+    /// ```
+    pub fn ci_annotations(mut self, format: CiFormat) -> Self {
+        self.ci_annotations = format;
+        self
+    }
+
+    /// Panic on likely bugs in how the program exits, in debug builds
+    ///
+    /// This checks, before exiting:
+    /// - The code is [portable][crate::Code::is_portable], as Unix would silently truncate it, see
+    ///   [`Code::normalize`][crate::Code::normalize].  This is checked after any
+    ///   [override][Reporter::env_override].
+    /// - An [`Exit`][crate::Exit] with [`Code::SUCCESS`][crate::Code::SUCCESS] has no messages,
+    ///   as a failure being reported while exiting successfully is likely a logic bug, like
+    ///   returning `Err` where `Ok` was meant.
+    ///
+    /// The panic happens after the messages are reported and describes the misuse.  Without
+    /// `debug_assertions`, like in release builds, nothing is checked.
+    ///
+    /// Example:
+    ///
+    /// ```should_panic
+    /// let reporter = proc_exit::Reporter::new().debug_assertions(true);
+    /// let _ = reporter.report(Err(proc_exit::Code::SUCCESS.with_message("oops")));
+    /// ```
+    pub fn debug_assertions(mut self, yes: bool) -> Self {
+        self.debug_assertions = yes;
+        self
+    }
+
+    /// Neutralize terminal control sequences in messages
+    ///
+    /// Messages are written verbatim by default.  When they come from an untrusted source, like a
+    /// child's output, control characters and ANSI escape sequences can garble the terminal or,
+    /// with some terminals, be used to exploit it.  With this, escape sequences are removed and
+    /// other control characters, besides newlines and tabs, are written escaped, like `\u{7}`.
+    /// This applies to messages, hints, and causes but not to the
+    /// [program name][Reporter::program_name] or [line prefix][Reporter::line_prefix].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().sanitize(true);
+    /// ```
+    pub fn sanitize(mut self, yes: bool) -> Self {
+        self.sanitize = yes;
+        self
+    }
+
+    /// Also append failures to the file at `path`, like for an audit trail
+    ///
+    /// Failures are written to `stderr` first and then to the file, with the file created if
+    /// needed.  The file gets the same output as `stderr` would, written with a single call so
+    /// concurrent processes don't interleave, except that [`Verbosity::Quiet`] and
+    /// `Syslog::Instead` don't silence it.  Nothing is written on success.
+    ///
+    /// Writing to the file is best-effort: if it fails, the failure is still reported to the
+    /// other sinks and exits with its own code.  See [`Reporter::tee_strict`] to not ignore it.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().tee("mytool.log");
+    /// ```
+    pub fn tee(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.tee = Some(path.into());
+        self
+    }
+
+    /// Fail when the [`Reporter::tee`] file can't be written
+    ///
+    /// The file's error is written to `stderr`, after the failure being reported, and the process
+    /// exits with [`sysexits::IO_ERR`][crate::sysexits::IO_ERR] instead of the failure's code.
+    pub fn tee_strict(mut self, yes: bool) -> Self {
+        self.tee_strict = yes;
+        self
+    }
+
+    /// Report the [description][crate::Code::describe] of well-known codes that have no message
+    ///
+    /// By default, an [`Exit`][crate::Exit] without a message exits silently, assuming the
+    /// failure was reported another way.  With this, well-known codes are still explained while
+    /// other codes stay silent.  Explicit messages are always reported as-is.
+    pub fn describe_known(mut self, yes: bool) -> Self {
+        self.describe_known = yes;
+        self
+    }
+
+    /// Exit with the code in the environment variable `name`, when set
+    ///
+    /// This is an escape hatch for testing how wrappers react to codes.  The value is parsed like
+    /// [`Code`'s `FromStr`][crate::Code#impl-FromStr-for-Code], accepting integers and names.
+    /// When set and valid, it takes precedence over the result's code, including on success,
+    /// while messages are still reported.  Unset or invalid values are ignored.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().env_override("MYTOOL_FORCE_EXIT");
+    /// ```
+    pub fn env_override(mut self, name: impl Into<String>) -> Self {
+        self.env_override = Some(name.into());
+        self
+    }
+
+    /// Wrap messages to the width of the terminal
+    ///
+    /// When `stderr` is not a terminal or its width can't be determined, messages are written
+    /// unwrapped.
+    #[cfg(feature = "wrap")]
+    pub fn wrap(mut self, yes: bool) -> Self {
+        self.wrap = yes;
+        self
+    }
+
+    /// Also write the code, in decimal and followed by a newline, to `fd`
+    ///
+    /// This lets a supervisor learn the intended code even when it can't observe the exit
+    /// status.  The code is written on success as well.
+    ///
+    /// `fd` must stay open for as long as the `Reporter` is used, which is why it must be
+    /// `'static`, e.g. an inherited descriptor:
+    ///
+    /// ```no_run
+    /// // SAFETY: the supervisor passes FD 3 to us and it is never closed
+    /// let fd = unsafe { std::os::fd::BorrowedFd::borrow_raw(3) };
+    /// let reporter = proc_exit::Reporter::new().status_fd(fd);
+    /// reporter.exit(proc_exit::Code::SUCCESS.ok());
+    /// ```
+    #[cfg(unix)]
+    pub fn status_fd(mut self, fd: std::os::fd::BorrowedFd<'static>) -> Self {
+        self.status_fd = Some(fd);
+        self
+    }
+
+    /// Write messages to the system log
+    ///
+    /// Each line is logged with a priority derived from the code's
+    /// [category][crate::Code::category]:
+    ///
+    /// | Priority      | Categories |
+    /// |---------------|------------|
+    /// | `LOG_INFO`    | [`Success`][crate::CodeCategory::Success], like a message on `Ok` |
+    /// | `LOG_NOTICE`  | [`Usage`][crate::CodeCategory::Usage] |
+    /// | `LOG_ERR`     | [`GenericFailure`][crate::CodeCategory::GenericFailure], [`Sysexit`][crate::CodeCategory::Sysexit], [`Other`][crate::CodeCategory::Other] |
+    /// | `LOG_CRIT`    | [`ShellReserved`][crate::CodeCategory::ShellReserved], [`Signal`][crate::CodeCategory::Signal] |
+    ///
+    /// The message is logged without the [program name][Reporter::program_name] as `syslog`
+    /// adds its own, and independent of the [`Verbosity`], which only applies to `stderr`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{Reporter, Syslog};
+    ///
+    /// let reporter = Reporter::new().syslog(Syslog::Also);
+    /// ```
+    #[cfg(all(unix, feature = "syslog"))]
+    pub fn syslog(mut self, syslog: Syslog) -> Self {
+        self.syslog = syslog;
+        self
+    }
+
+    /// Skip writing a message identical to the one before it
+    ///
+    /// This is for calling [`Reporter::report`] in a loop that may fail the same way repeatedly.
+    /// Only the last written output is remembered, so `a`, `a`, `b`, `a` writes `a`, `b`, `a`.
+    /// The number of skipped repeats is written as `(repeated N times)` before the next different
+    /// message, on success, on [`Reporter::exit`], or on [`Reporter::flush_repeated`].
+    ///
+    /// Clones of the `Reporter` share what was last written.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let reporter = proc_exit::Reporter::new().dedupe(true);
+    /// for _ in 0..3 {
+    ///     let _ = reporter.report(Err(proc_exit::sysexits::TEMP_FAIL.with_message("server busy")));
+    /// }
+    /// reporter.flush_repeated();
+    /// ```
+    pub fn dedupe(mut self, yes: bool) -> Self {
+        self.dedupe = yes.then(Default::default);
+        self
+    }
+
+    /// Write the number of skipped repeats, see [`Reporter::dedupe`]
+    pub fn flush_repeated(&self) {
+        if let Some(dedupe) = &self.dedupe {
+            let mut dedupe = dedupe
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let _ = dedupe.flush(&mut std::io::stderr().lock());
+        }
+    }
+
+    /// Report any error message and exit.
+    pub fn exit(&self, result: crate::ExitResult) -> ! {
+        let code = self.report(result);
+        self.flush_repeated();
+        code.process_exit()
+    }
+
+    /// Report, delegating exiting to the caller.
+    ///
+    /// With the `tracing` feature, this also emits a `tracing` event with the target
+    /// `proc_exit` and the fields:
+    /// - `code`: the [raw][crate::Code::as_raw] code that will be exited with
+    /// - `category`: the code's [category][crate::Code::category]
+    /// - `exit_message`: the [messages][crate::Exit::to_message_string], when there are any,
+    ///   [truncated][Reporter::max_message_bytes] like on `stderr`
+    ///
+    /// The event's own message is `exit`.  The event is at the `ERROR` level for failures and `DEBUG` for success.
+    #[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
+    pub fn report(&self, result: crate::ExitResult) -> crate::Code {
+        let _locale = self.locale.as_deref().map(crate::locale::Scope::new);
+        let check = cfg!(debug_assertions) && self.debug_assertions;
+        let success_with_message = check
+            && result
+                .as_ref()
+                .err()
+                .is_some_and(|err| err.code().is_ok() && err.has_message());
+        #[cfg(feature = "tracing")]
+        let message = result
+            .as_ref()
+            .err()
+            .and_then(|err| err.to_message_string())
+            .map(|mut msg| {
+                if let Some(max) = self.max_message_bytes {
+                    truncate(&mut msg, max);
+                }
+                msg
+            });
+        let code = match result {
+            Ok(()) => {
+                self.flush_repeated();
+                crate::Code::SUCCESS
+            }
+            Err(err) => {
+                if self.to_stderr() {
+                    // At this point, we might be exiting due to a broken pipe, just do our best
+                    // and move on.
+                    let _ = match &self.dedupe {
+                        Some(dedupe) => self.write_deduped(&err, dedupe),
+                        None => self.write_to(&err, &mut std::io::stderr().lock()),
+                    };
+                    if self.ci_annotations != CiFormat::None && self.verbosity != Verbosity::Quiet {
+                        let _ = self.write_annotation(&err, &mut std::io::stderr().lock());
+                    }
+                }
+                let mut code = err.code();
+                if let Some(path) = &self.tee {
+                    if let Err(tee_err) = self.write_tee(&err, path) {
+                        if self.tee_strict {
+                            let _ = writeln!(
+                                std::io::stderr().lock(),
+                                "failed to write to `{}`: {tee_err}",
+                                path.display()
+                            );
+                            code = crate::sysexits::IO_ERR;
+                        }
+                    }
+                }
+                #[cfg(all(unix, feature = "syslog"))]
+                if self.syslog != Syslog::Off {
+                    self.write_syslog(&err);
+                }
+                code
+            }
+        };
+        let code = self.overridden_code().unwrap_or(code);
+        #[cfg(feature = "tracing")]
+        trace_exit(code, message.as_deref());
+        if self.warn_nonportable && !code.is_portable() {
+            static WARNED: std::sync::atomic::AtomicBool =
+                std::sync::atomic::AtomicBool::new(false);
+            if !WARNED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                let _ = writeln!(
+                    std::io::stderr().lock(),
+                    "warning: exit code {} is not portable, Unix will report it as {}",
+                    code.as_raw(),
+                    code.as_raw() & 0xff
+                );
+            }
+        }
+        if self.summary_line && !self.json && self.verbosity != Verbosity::Quiet && self.to_stderr()
+        {
+            let _ = writeln!(
+                std::io::stderr().lock(),
+                "exit: {} ({})",
+                code.as_raw(),
+                code.category()
+            );
+        }
+        #[cfg(unix)]
+        if let Some(fd) = self.status_fd {
+            // The reading end may already be closed, which shouldn't change how we exit
+            let _ = write_status(fd, code);
+        }
+        if check {
+            assert!(
+                code.is_portable(),
+                "exit code {} is not portable, Unix will report it as {}",
+                code.as_raw(),
+                code.as_raw() & 0xff
+            );
+            assert!(
+                !success_with_message,
+                "exiting successfully with an error message, was `Ok` meant?"
+            );
+        }
+        code
+    }
+
+    /// Report like [`Reporter::report`], writing `note` as a warning on success
+    ///
+    /// This is for success that deserves attention, like using a deprecated flag.  The note is
+    /// written as `warning: <note>` to `stderr`, with any [program name][Reporter::program_name],
+    /// unless [`Verbosity::Quiet`].  The code stays [`Code::SUCCESS`][crate::Code::SUCCESS], so
+    /// scripts checking for `0` are unaffected.  On failure, the note is dropped.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// let result = proc_exit::Code::SUCCESS.ok();
+    /// let code = proc_exit::Reporter::new()
+    ///     .report_success_with(result, "`--old-flag` is deprecated, use `--new-flag`");
+    /// assert_eq!(code, proc_exit::Code::SUCCESS);
+    /// ```
+    #[must_use = "the code is only used once passed to `Code::process_exit` or returned from `main`"]
+    pub fn report_success_with<D: std::fmt::Display>(
+        &self,
+        result: crate::ExitResult,
+        note: D,
+    ) -> crate::Code {
+        if result.is_ok() && self.verbosity != Verbosity::Quiet {
+            // A note that can't be written isn't worth failing a successful run over
+            let _ = self.write_note_to(&note, &mut std::io::stderr().lock());
+        }
+        self.report(result)
+    }
+
+    fn write_note_to(
+        &self,
+        note: &dyn std::fmt::Display,
+        w: &mut impl Write,
+    ) -> std::io::Result<()> {
+        if let Some(name) = self.program_name.as_deref() {
+            write!(w, "{name}: ")?;
+        }
+        writeln!(w, "warning: {note}")
+    }
+
+    fn write_deduped(
+        &self,
+        err: &crate::Exit,
+        dedupe: &std::sync::Mutex<Dedupe>,
+    ) -> std::io::Result<()> {
+        let mut rendered = Vec::new();
+        self.write_to(err, &mut rendered)?;
+        if rendered.is_empty() {
+            return Ok(());
+        }
+        let mut dedupe = dedupe
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if dedupe.last == rendered {
+            dedupe.repeated += 1;
+            return Ok(());
+        }
+        let mut stderr = std::io::stderr().lock();
+        dedupe.flush(&mut stderr)?;
+        stderr.write_all(&rendered)?;
+        dedupe.last = rendered;
+        Ok(())
+    }
+
+    fn write_annotation(&self, err: &crate::Exit, w: &mut impl Write) -> std::io::Result<()> {
+        let Some(mut msg) = self.render_messages_with(err, self.program_name.as_deref(), None)
+        else {
+            return Ok(());
+        };
+        if let Some(max) = self.max_message_bytes {
+            truncate(&mut msg, max);
+        }
+        match self.ci_annotations {
+            CiFormat::None => Ok(()),
+            CiFormat::GitHubActions => {
+                let msg = msg
+                    .replace('%', "%25")
+                    .replace('\r', "%0D")
+                    .replace('\n', "%0A");
+                writeln!(w, "::error::{msg}")
+            }
+            CiFormat::AzurePipelines => {
+                let msg = msg
+                    .replace('%', "%AZP25")
+                    .replace('\r', "%0D")
+                    .replace('\n', "%0A");
+                writeln!(w, "##vso[task.logissue type=error]{msg}")
+            }
+        }
+    }
+
+    fn write_tee(&self, err: &crate::Exit, path: &std::path::Path) -> std::io::Result<()> {
+        let mut rendered = Vec::new();
+        self.render_to(err, &mut rendered)?;
+        if rendered.is_empty() {
+            return Ok(());
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        file.write_all(&rendered)
+    }
+
+    fn write_to(&self, err: &crate::Exit, w: &mut impl Write) -> std::io::Result<()> {
+        if self.verbosity == Verbosity::Quiet {
+            return Ok(());
+        }
+        self.render_to(err, w)
+    }
+
+    fn render_to(&self, err: &crate::Exit, w: &mut impl Write) -> std::io::Result<()> {
+        if self.json {
+            return crate::json::write_exit(err, self.max_message_bytes, w);
+        }
+        let wrap_width = self.wrap_width();
+        if !self.describe_known
+            && self.program_name.is_none()
+            && self.line_prefix.is_none()
+            && self.max_message_bytes.is_none()
+            && !self.sanitize
+            && wrap_width.is_none()
+        {
+            err.write_to(w)?;
+        } else {
+            if let Some(mut msg) = self.render_messages(err) {
+                if let Some(max) = self.max_message_bytes {
+                    truncate(&mut msg, max);
+                }
+                #[cfg(feature = "wrap")]
+                if let Some(width) = wrap_width {
+                    msg = textwrap::fill(&msg, width);
+                }
+                writeln!(w, "{msg}")?;
+            }
+            match err.hint() {
+                Some(hint) if self.sanitize => {
+                    writeln!(w, "hint: {}", sanitize(&hint.to_string()))?;
+                }
+                _ => err.write_hint_to(w)?,
+            }
+        }
+        if self.verbosity == Verbosity::Verbose {
+            let mut cause = err.first_cause();
+            while let Some(current) = cause {
+                if self.sanitize {
+                    writeln!(w, "caused by: {}", sanitize(&current.to_string()))?;
+                } else {
+                    writeln!(w, "caused by: {current}")?;
+                }
+                cause = current.source();
+            }
+            #[cfg(feature = "backtrace")]
+            if let Some(backtrace) = err.backtrace() {
+                writeln!(w, "backtrace:\n{backtrace}")?;
+            }
+            let code = err.code();
+            write!(w, "exit code {}", code.as_raw())?;
+            if let Some(name) = code.name() {
+                write!(w, " ({name})")?;
+            }
+            writeln!(w, ": {}", code.category())?;
+        }
+        Ok(())
+    }
+
+    #[cfg(all(unix, feature = "syslog"))]
+    fn to_stderr(&self) -> bool {
+        self.syslog != Syslog::Instead
+    }
+
+    #[cfg(not(all(unix, feature = "syslog")))]
+    fn to_stderr(&self) -> bool {
+        true
+    }
+
+    #[cfg(all(unix, feature = "syslog"))]
+    fn write_syslog(&self, err: &crate::Exit) {
+        let Some(mut msg) = self.render_messages_with(err, None, None) else {
+            return;
+        };
+        if let Some(max) = self.max_message_bytes {
+            truncate(&mut msg, max);
+        }
+        let priority = match err.code().category() {
+            crate::CodeCategory::Success => libc::LOG_INFO,
+            crate::CodeCategory::Usage => libc::LOG_NOTICE,
+            crate::CodeCategory::GenericFailure
+            | crate::CodeCategory::Sysexit
+            | crate::CodeCategory::Other => libc::LOG_ERR,
+            crate::CodeCategory::ShellReserved | crate::CodeCategory::Signal => libc::LOG_CRIT,
+        };
+        for line in msg.lines() {
+            let Ok(line) = std::ffi::CString::new(line.replace('\0', "")) else {
+                continue;
+            };
+            // SAFETY: the format string is a literal consuming the one, NUL-terminated, argument
+            unsafe {
+                libc::syslog(priority, b"%s\0".as_ptr().cast(), line.as_ptr());
+            }
+        }
+    }
+
+    fn render_messages(&self, err: &crate::Exit) -> Option<String> {
+        self.render_messages_with(
+            err,
+            self.program_name.as_deref(),
+            self.line_prefix.as_deref(),
+        )
+    }
+
+    fn render_messages_with(
+        &self,
+        err: &crate::Exit,
+        program_name: Option<&str>,
+        line_prefix: Option<&str>,
+    ) -> Option<String> {
+        let description = if self.describe_known && !err.has_message() {
+            err.code().describe()
+        } else {
+            None
+        };
+        let mut rendered = String::new();
+        for (i, msg) in description
+            .iter()
+            .map(|d| d as &dyn std::fmt::Display)
+            .chain(err.messages())
+            .enumerate()
+        {
+            if !rendered.is_empty() {
+                rendered.push('\n');
+            }
+            if let Some(name) = program_name {
+                rendered.push_str(name);
+                rendered.push_str(": ");
+            }
+            let mut msg = msg.to_string();
+            if self.sanitize {
+                msg = sanitize(&msg);
+            }
+            if i == 0 {
+                if let Some(app_code) = err.app_code() {
+                    msg = format!("error[{app_code}]: {msg}");
+                }
+            }
+            match line_prefix {
+                Some(prefix) => {
+                    for (i, line) in msg.lines().enumerate() {
+                        if i != 0 {
+                            rendered.push('\n');
+                        }
+                        rendered.push_str(prefix);
+                        rendered.push_str(line);
+                    }
+                }
+                None => rendered.push_str(&msg),
+            }
+        }
+        if rendered.is_empty() {
+            None
+        } else {
+            Some(rendered)
+        }
+    }
+
+    fn overridden_code(&self) -> Option<crate::Code> {
+        let name = self.env_override.as_deref()?;
+        crate::Code::parse_env(name)?.ok()
+    }
+
+    #[cfg(feature = "wrap")]
+    fn wrap_width(&self) -> Option<usize> {
+        use std::io::IsTerminal as _;
+
+        if !self.wrap || !std::io::stderr().is_terminal() {
+            return None;
+        }
+        let (terminal_size::Width(width), _) = terminal_size::terminal_size_of(std::io::stderr())?;
+        Some(width.into())
+    }
+
+    #[cfg(not(feature = "wrap"))]
+    fn wrap_width(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+struct Dedupe {
+    last: Vec<u8>,
+    repeated: usize,
+}
+
+impl Dedupe {
+    fn flush(&mut self, w: &mut impl Write) -> std::io::Result<()> {
+        let repeated = std::mem::take(&mut self.repeated);
+        match repeated {
+            0 => {}
+            1 => writeln!(w, "(repeated 1 time)")?,
+            _ => writeln!(w, "(repeated {repeated} times)")?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn trace_exit(code: crate::Code, message: Option<&str>) {
+    if code.is_ok() {
+        tracing::debug!(
+            target: "proc_exit",
+            code = code.as_raw(),
+            category = %code.category(),
+            exit_message = message,
+            "exit"
+        );
+    } else {
+        tracing::error!(
+            target: "proc_exit",
+            code = code.as_raw(),
+            category = %code.category(),
+            exit_message = message,
+            "exit"
+        );
+    }
+}
+
+/// Remove ANSI escape sequences and escape other control characters, except `\n` and `\t`
+fn sanitize(msg: &str) -> String {
+    const ESC: char = '\u{1b}';
+    const BEL: char = '\u{7}';
+
+    let mut sanitized = String::with_capacity(msg.len());
+    let mut chars = msg.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' | '\t' => sanitized.push(c),
+            // CSI, e.g. colors: parameters and intermediates up to a final byte in `@..=~`
+            ESC if chars.peek() == Some(&'[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC, e.g. titles and hyperlinks: up to `BEL` or `ESC \`
+            ESC if chars.peek() == Some(&']') => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == BEL {
+                        break;
+                    }
+                    if c == ESC && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // Other escapes are a single character
+            ESC => {
+                chars.next();
+            }
+            c if c.is_control() => sanitized.extend(c.escape_unicode()),
+            c => sanitized.push(c),
+        }
+    }
+    sanitized
+}
+
+pub(crate) fn truncate(msg: &mut String, max: usize) {
+    if msg.len() <= max {
+        return;
+    }
+    const ELLIPSIS: char = '…';
+    let ellipsis = max >= ELLIPSIS.len_utf8();
+    let mut end = if ellipsis {
+        max - ELLIPSIS.len_utf8()
+    } else {
+        max
+    };
+    while !msg.is_char_boundary(end) {
+        end -= 1;
+    }
+    msg.truncate(end);
+    if ellipsis {
+        msg.push(ELLIPSIS);
+    }
+}
+
+#[cfg(unix)]
+fn write_status(fd: std::os::fd::BorrowedFd<'_>, code: crate::Code) -> std::io::Result<()> {
+    let mut file = std::fs::File::from(fd.try_clone_to_owned()?);
+    writeln!(file, "{}", code.as_raw())
+}
+
+/// How much a [`Reporter`] writes on failure
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Verbosity {
+    /// Write nothing, only exit with the code, e.g. for `--quiet`
+    Quiet,
+    /// Write the [`Exit`][crate::Exit]'s messages
+    #[default]
+    Normal,
+    /// Write the messages, followed by any [causes][crate::Exit::source], the code and its
+    /// [category][crate::Code::category], e.g. for `--verbose`
+    Verbose,
+}
+
+/// The CI platform to write annotations for, see [`Reporter::ci_annotations`]
+///
+/// GitLab CI has no annotations written to the job log, it reads them from report artifacts
+/// instead, so it has no format here.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CiFormat {
+    /// Write no annotations
+    #[default]
+    None,
+    /// [GitHub Actions](https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message)
+    ///
+    /// Writes `::error::<message>`, with `%`, `\r`, and `\n` escaped as `%25`, `%0D`, and `%0A`.
+    GitHubActions,
+    /// [Azure Pipelines](https://learn.microsoft.com/en-us/azure/devops/pipelines/scripts/logging-commands#logissue-log-an-error-or-warning)
+    ///
+    /// Writes `##vso[task.logissue type=error]<message>`, with `%`, `\r`, and `\n` escaped as
+    /// `%AZP25`, `%0D`, and `%0A`.
+    AzurePipelines,
+}
+
+impl CiFormat {
+    /// The platform the process is running under, from its environment variables
+    ///
+    /// This is [`CiFormat::GitHubActions`] when `GITHUB_ACTIONS` is `true`,
+    /// [`CiFormat::AzurePipelines`] when `TF_BUILD` is `True`, and [`CiFormat::None`] otherwise.
+    pub fn detect() -> Self {
+        let is = |name: &str, value: &str| {
+            std::env::var(name).is_ok_and(|actual| actual.eq_ignore_ascii_case(value))
+        };
+        if is("GITHUB_ACTIONS", "true") {
+            Self::GitHubActions
+        } else if is("TF_BUILD", "true") {
+            Self::AzurePipelines
+        } else {
+            Self::None
+        }
+    }
+}
+
+/// Where a [`Reporter`] writes besides `stderr`, see [`Reporter::syslog`]
+#[cfg(all(unix, feature = "syslog"))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Syslog {
+    /// Only write to `stderr`
+    #[default]
+    Off,
+    /// Write to both `stderr` and the system log
+    Also,
+    /// Write to the system log instead of `stderr`
+    Instead,
+}
@@ -0,0 +1,29 @@
+//! [Rust](https://www.rust-lang.org) toolchain exit codes
+//!
+//! For tools that wrap `cargo` or `rustc`, like an `xtask`.
+//!
+//! **Note:** these overlap, so a code can't tell a failed build from a panic:
+//! - [`COMPILER_ERROR`] is [`PANIC`]
+//! - `rustc` itself reports compile errors with [`Code::FAILURE`][crate::Code::FAILURE],
+//!   reserving [`PANIC`] for internal compiler errors
+//!
+//! Example:
+//!
+//! ```no_run
+//! let status = std::process::Command::new("cargo").arg("build").status().unwrap();
+//! let code = proc_exit::Code::from_status(status);
+//! if code == proc_exit::rust::PANIC {
+//!     // Build failed or `cargo` panicked
+//! }
+//! ```
+
+/// A Rust program panicked
+///
+/// This is the code the Rust runtime exits with when `main` panics.  `rustc` uses it for an
+/// internal compiler error.
+pub const PANIC: crate::Code = crate::Code::new(101);
+
+/// A `cargo` command failed, including failing to compile
+///
+/// See the [cargo book](https://doc.rust-lang.org/cargo/commands/cargo.html#exit-status).
+pub const COMPILER_ERROR: crate::Code = crate::Code::new(101);
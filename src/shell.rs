@@ -0,0 +1,61 @@
+/// Shell family, which affects how exit codes are interpreted
+///
+/// For example, `bash` reserves [`bash::NOT_FOUND`][crate::bash::NOT_FOUND] while PowerShell
+/// has no such convention.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Shell {
+    /// `bash`
+    Bash,
+    /// `zsh`
+    Zsh,
+    /// `fish`
+    Fish,
+    /// A POSIX `sh`, like `dash` or `ash`
+    Sh,
+    /// PowerShell, either `pwsh` or `powershell`
+    PowerShell,
+    /// Could not be determined
+    Unknown,
+}
+
+impl Shell {
+    /// Identify the shell from its program path or name
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::Shell;
+    ///
+    /// assert_eq!(Shell::from_program("/usr/bin/fish"), Shell::Fish);
+    /// assert_eq!(Shell::from_program("pwsh.exe"), Shell::PowerShell);
+    /// assert_eq!(Shell::from_program("/bin/tcsh"), Shell::Unknown);
+    /// ```
+    pub fn from_program(program: &str) -> Self {
+        let name = std::path::Path::new(program)
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        match name {
+            "bash" => Self::Bash,
+            "zsh" => Self::Zsh,
+            "fish" => Self::Fish,
+            "sh" | "dash" | "ash" => Self::Sh,
+            "pwsh" | "powershell" => Self::PowerShell,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Best-effort detection of the user's shell
+///
+/// This reads `$SHELL`, which is the user's login shell.  That is not necessarily the shell that
+/// ran the current process, like when a `bash` user runs a script with `fish`, and it is usually
+/// unset on Windows, giving [`Shell::Unknown`].  Inspecting the parent process is not done as it
+/// is platform-specific and the parent is often not a shell at all (e.g. `cargo`, an IDE, or a
+/// CI runner).
+pub fn detect_shell() -> Shell {
+    std::env::var("SHELL")
+        .map(|shell| Shell::from_program(&shell))
+        .unwrap_or(Shell::Unknown)
+}
@@ -0,0 +1,94 @@
+use std::io::Write;
+
+/// Tally of failures by [`CodeCategory`][crate::CodeCategory], for an end-of-run summary
+///
+/// Only the [`Exit`][crate::Exit]s' codes are kept; report their messages as they happen, if
+/// needed.
+///
+/// Example:
+///
+/// ```
+/// let mut summary = proc_exit::ExitSummary::new();
+/// summary.push(proc_exit::bash::USAGE.as_exit());
+/// summary.push(proc_exit::bash::SIGINT.with_message("interrupted"));
+/// summary.push(proc_exit::sysexits::USAGE_ERR.as_exit());
+///
+/// let mut buffer = Vec::new();
+/// let code = summary.render(&mut buffer).unwrap();
+/// assert_eq!(code, proc_exit::bash::SIGINT);
+/// assert_eq!(buffer, b"usage error: 2, terminated by signal: 1\n");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExitSummary {
+    counts: Vec<(crate::CodeCategory, usize)>,
+    code: crate::Code,
+}
+
+impl ExitSummary {
+    /// Nothing has failed yet
+    pub fn new() -> Self {
+        Self {
+            counts: Vec::new(),
+            code: crate::Code::SUCCESS,
+        }
+    }
+
+    /// Count `exit` under its [category][crate::Code::category]
+    ///
+    /// Its messages are dropped.  An `Exit` with [`Code::SUCCESS`][crate::Code::SUCCESS] is not
+    /// counted.
+    pub fn push(&mut self, exit: crate::Exit) {
+        let code = exit.code();
+        if code.is_ok() {
+            return;
+        }
+        self.code = self.code.worst(code);
+        let category = code.category();
+        match self.counts.iter_mut().find(|(c, _)| *c == category) {
+            Some((_, count)) => *count += 1,
+            None => self.counts.push((category, 1)),
+        }
+    }
+
+    /// Whether no failures were pushed
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The [worst][crate::Code::worst] code pushed, or [`Code::SUCCESS`][crate::Code::SUCCESS]
+    pub fn code(&self) -> crate::Code {
+        self.code
+    }
+
+    /// Write the summary, returning the [worst][ExitSummary::code] code
+    ///
+    /// The summary is a single line of `<category>: <count>`, separated by `, `, in the order each
+    /// category was first pushed.  Nothing is written when empty.
+    pub fn render(&self, w: &mut impl Write) -> std::io::Result<crate::Code> {
+        if !self.is_empty() {
+            for (i, (category, count)) in self.counts.iter().enumerate() {
+                if i != 0 {
+                    write!(w, ", ")?;
+                }
+                write!(w, "{category}: {count}")?;
+            }
+            writeln!(w)?;
+        }
+        Ok(self.code)
+    }
+}
+
+impl Default for ExitSummary {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<crate::Exit> for ExitSummary {
+    fn extend<I: IntoIterator<Item = crate::Exit>>(&mut self, iter: I) {
+        for exit in iter {
+            self.push(exit);
+        }
+    }
+}
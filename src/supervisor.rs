@@ -0,0 +1,78 @@
+//! Modeling children whose [`Code`][crate::Code] isn't known yet
+//!
+//! A supervisor tracking long-running children needs to represent one that is still running.
+//! Rather than reserving a real code as a sentinel, track an [`Outcome`].
+//!
+//! Example:
+//!
+//! ```
+//! use proc_exit::supervisor::Outcome;
+//!
+//! let mut child = std::process::Command::new("true").spawn().unwrap();
+//! let mut outcome = Outcome::Pending;
+//! while outcome.is_pending() {
+//!     outcome = Outcome::from_try_wait(child.try_wait().unwrap());
+//! }
+//! assert_eq!(outcome.finished_code(), Some(proc_exit::Code::SUCCESS));
+//! ```
+
+/// Whether a child has finished and, if so, with which [`Code`][crate::Code]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Outcome {
+    /// The child is still running
+    #[default]
+    Pending,
+    /// The child exited with the code
+    Finished(crate::Code),
+}
+
+impl Outcome {
+    /// Convert the result of [`Child::try_wait`][std::process::Child::try_wait]
+    ///
+    /// The status is converted with [`Code::from_status`][crate::Code::from_status].
+    #[inline]
+    pub fn from_try_wait(status: Option<std::process::ExitStatus>) -> Self {
+        match status {
+            Some(status) => Self::Finished(crate::Code::from_status(status)),
+            None => Self::Pending,
+        }
+    }
+
+    /// The child's code, once it has finished
+    #[inline]
+    pub const fn finished_code(self) -> Option<crate::Code> {
+        match self {
+            Self::Pending => None,
+            Self::Finished(code) => Some(code),
+        }
+    }
+
+    /// Determines if the child is still running
+    #[inline]
+    pub const fn is_pending(self) -> bool {
+        matches!(self, Self::Pending)
+    }
+
+    /// Determines if the child has finished, successfully or not
+    #[inline]
+    pub const fn is_finished(self) -> bool {
+        matches!(self, Self::Finished(_))
+    }
+}
+
+impl From<crate::Code> for Outcome {
+    #[inline]
+    fn from(code: crate::Code) -> Self {
+        Self::Finished(code)
+    }
+}
+
+impl From<Option<crate::Code>> for Outcome {
+    #[inline]
+    fn from(code: Option<crate::Code>) -> Self {
+        match code {
+            Some(code) => Self::Finished(code),
+            None => Self::Pending,
+        }
+    }
+}
@@ -29,6 +29,23 @@
 /// ```
 pub trait ToSysexitsResultExt<T> {
     /// Convert an Error into an [`Exit`][crate::Exit]
+    ///
+    /// When the error wraps another, like with [`std::io::Error::new`], the wrapped error is kept
+    /// as the [source][crate::Exit::source] so its causes are available.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::prelude::*;
+    ///
+    /// let inner = "x".parse::<u8>().unwrap_err();
+    /// let result: Result<(), _> = Err(std::io::Error::new(std::io::ErrorKind::InvalidData, inner));
+    /// let exit = result.to_sysexits().unwrap_err();
+    /// assert_eq!(exit.code(), proc_exit::sysexits::DATA_ERR);
+    /// assert_eq!(exit.to_string(), "invalid digit found in string");
+    /// assert!(exit.source().is_some());
+    /// ```
+    #[must_use = "the failure is lost unless propagated, like with `?`"]
     fn to_sysexits(self) -> Result<T, crate::Exit>;
 }
 
@@ -36,15 +53,29 @@ impl<T> ToSysexitsResultExt<T> for Result<T, std::io::Error> {
     #[inline]
     fn to_sysexits(self) -> Result<T, crate::Exit> {
         self.map_err(|e| {
-            let kind = e.kind();
-            let code = io_to_sysexists(kind)
-                .or_else(|| crate::bash::io_to_signal(kind))
-                .unwrap_or(IO_ERR);
-            crate::Exit::new(code).with_message(e)
+            let code = io_to_code(e.kind());
+            if e.get_ref().is_some() {
+                // `Display` is the wrapped error's, so keep that as the message and the error
+                // itself as the source for its causes
+                let msg = e.to_string();
+                let exit = crate::Exit::new(code).with_message(msg);
+                match e.into_inner() {
+                    Some(inner) => exit.with_message_source(inner),
+                    None => exit,
+                }
+            } else {
+                crate::Exit::new(code).with_message(e)
+            }
         })
     }
 }
 
+pub(crate) fn io_to_code(kind: std::io::ErrorKind) -> crate::Code {
+    io_to_sysexists(kind)
+        .or_else(|| crate::bash::io_to_signal(kind))
+        .unwrap_or(IO_ERR)
+}
+
 /// Convert [`std::io::ErrorKind`] to a [`Code`][crate::Code]
 #[inline]
 pub fn io_to_sysexists(kind: std::io::ErrorKind) -> Option<crate::Code> {
@@ -134,3 +165,27 @@ pub const NO_PERM: crate::Code = crate::Code::new(77);
 
 /// Something was found in an unconfigured or misconfigured state.
 pub const CONFIG_ERR: crate::Code = crate::Code::new(78);
+
+/// Codes defined by sysexits, other than [`OK`]
+pub(crate) const fn is_sysexit(code: crate::Code) -> bool {
+    USAGE_ERR.as_raw() <= code.as_raw() && code.as_raw() <= CONFIG_ERR.as_raw()
+}
+
+pub(crate) const NAMES: &[crate::name::Entry] = &[
+    crate::name::Entry::new("EX_OK", OK, "success"),
+    crate::name::Entry::new("EX_USAGE", USAGE_ERR, "command line usage error"),
+    crate::name::Entry::new("EX_DATAERR", DATA_ERR, "data format error"),
+    crate::name::Entry::new("EX_NOINPUT", NO_INPUT, "cannot open input"),
+    crate::name::Entry::new("EX_NOUSER", NO_USER, "addressee unknown"),
+    crate::name::Entry::new("EX_NOHOST", NO_HOST, "host name unknown"),
+    crate::name::Entry::new("EX_UNAVAILABLE", SERVICE_UNAVAILABLE, "service unavailable"),
+    crate::name::Entry::new("EX_SOFTWARE", SOFTWARE_ERR, "internal software error"),
+    crate::name::Entry::new("EX_OSERR", OS_ERR, "system error"),
+    crate::name::Entry::new("EX_OSFILE", OS_FILE_ERR, "critical OS file missing"),
+    crate::name::Entry::new("EX_CANTCREAT", CANT_CREAT, "can't create output file"),
+    crate::name::Entry::new("EX_IOERR", IO_ERR, "input/output error"),
+    crate::name::Entry::new("EX_TEMPFAIL", TEMP_FAIL, "temporary failure"),
+    crate::name::Entry::new("EX_PROTOCOL", PROTOCOL_ERR, "remote error in protocol"),
+    crate::name::Entry::new("EX_NOPERM", NO_PERM, "permission denied"),
+    crate::name::Entry::new("EX_CONFIG", CONFIG_ERR, "configuration error"),
+];
@@ -0,0 +1,36 @@
+/// Every [`sysexits::ExitCode`][::sysexits::ExitCode] has a matching [`sysexits`][crate::sysexits]
+/// code
+///
+/// Example:
+///
+/// ```
+/// let code = proc_exit::Code::from(sysexits::ExitCode::Usage);
+/// assert_eq!(code, proc_exit::sysexits::USAGE_ERR);
+/// ```
+impl From<::sysexits::ExitCode> for crate::Code {
+    #[inline]
+    fn from(code: ::sysexits::ExitCode) -> Self {
+        Self::new(i32::from(code))
+    }
+}
+
+/// Only [`sysexits`][crate::sysexits] codes convert
+///
+/// Other codes, including [`Code::FAILURE`][crate::Code::FAILURE] and signals, are an error as the
+/// enum can't represent them.
+///
+/// Example:
+///
+/// ```
+/// let code = sysexits::ExitCode::try_from(proc_exit::sysexits::CONFIG_ERR).unwrap();
+/// assert_eq!(code, sysexits::ExitCode::Config);
+/// assert!(sysexits::ExitCode::try_from(proc_exit::Code::FAILURE).is_err());
+/// ```
+impl TryFrom<crate::Code> for ::sysexits::ExitCode {
+    type Error = ::sysexits::error::ExitCodeRangeError;
+
+    #[inline]
+    fn try_from(code: crate::Code) -> Result<Self, Self::Error> {
+        Self::try_from(code.as_raw())
+    }
+}
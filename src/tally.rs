@@ -0,0 +1,133 @@
+/// Outcomes of a test-runner-style batch, combined into one [`Code`][crate::Code]
+///
+/// See [`RunTally::exit_code`] for the convention used.
+///
+/// Example:
+///
+/// ```
+/// let mut tally = proc_exit::RunTally::new();
+/// tally.pass();
+/// tally.skip();
+/// assert_eq!(tally.exit_code(), proc_exit::Code::SUCCESS);
+///
+/// tally.fail();
+/// assert_eq!(tally.exit_code(), proc_exit::Code::FAILURE);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunTally {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+impl RunTally {
+    /// Nothing has run yet
+    pub const fn new() -> Self {
+        Self {
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+        }
+    }
+
+    /// Count a passing run
+    pub fn pass(&mut self) {
+        self.passed += 1;
+    }
+
+    /// Count a failing run
+    pub fn fail(&mut self) {
+        self.failed += 1;
+    }
+
+    /// Count a skipped run
+    pub fn skip(&mut self) {
+        self.skipped += 1;
+    }
+
+    /// Number of passing runs
+    pub const fn passed(&self) -> usize {
+        self.passed
+    }
+
+    /// Number of failing runs
+    pub const fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Number of skipped runs
+    pub const fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Combine the outcomes with [`TallyPolicy::DEFAULT`]
+    pub const fn exit_code(&self) -> crate::Code {
+        self.exit_code_with(TallyPolicy::DEFAULT)
+    }
+
+    /// Combine the outcomes
+    ///
+    /// In order:
+    /// - Any failure gives [`TallyPolicy::failed`]
+    /// - Nothing passing, whether everything was skipped or nothing ran, gives
+    ///   [`TallyPolicy::none_passed`]
+    /// - Otherwise, [`Code::SUCCESS`][crate::Code::SUCCESS]
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use proc_exit::{Code, RunTally, TallyPolicy};
+    ///
+    /// let mut tally = RunTally::new();
+    /// tally.skip();
+    /// assert_eq!(tally.exit_code(), proc_exit::sysexits::NO_INPUT);
+    ///
+    /// let policy = TallyPolicy::DEFAULT.none_passed(Code::SUCCESS);
+    /// assert_eq!(tally.exit_code_with(policy), Code::SUCCESS);
+    /// ```
+    pub const fn exit_code_with(&self, policy: TallyPolicy) -> crate::Code {
+        if self.failed != 0 {
+            policy.failed
+        } else if self.passed == 0 {
+            policy.none_passed
+        } else {
+            crate::Code::SUCCESS
+        }
+    }
+}
+
+/// Which [`Code`][crate::Code] a [`RunTally`] exits with
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TallyPolicy {
+    failed: crate::Code,
+    none_passed: crate::Code,
+}
+
+impl TallyPolicy {
+    /// Failures give [`Code::FAILURE`][crate::Code::FAILURE] and nothing passing gives
+    /// [`sysexits::NO_INPUT`][crate::sysexits::NO_INPUT], as it usually means a filter matched
+    /// nothing
+    pub const DEFAULT: Self = Self {
+        failed: crate::Code::FAILURE,
+        none_passed: crate::sysexits::NO_INPUT,
+    };
+
+    /// The code when any run failed
+    pub const fn failed(mut self, code: crate::Code) -> Self {
+        self.failed = code;
+        self
+    }
+
+    /// The code when no run failed or passed, e.g. all were skipped
+    pub const fn none_passed(mut self, code: crate::Code) -> Self {
+        self.none_passed = code;
+        self
+    }
+}
+
+impl Default for TallyPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}